@@ -1,9 +1,11 @@
 use std::{
     fmt,
     str::{from_utf8, FromStr, Utf8Error},
+    time::Duration,
 };
 
 use network_tables::Value;
+use smol_str::SmolStr;
 use thiserror::Error;
 
 use crate::widgets::tabs::Filter;
@@ -12,6 +14,7 @@ use crate::widgets::tabs::Filter;
 pub struct Write {
     entries: Vec<Entry>,
     filter: Option<Filter>,
+    delayed: Vec<(Duration, Entry)>,
 }
 
 impl Write {
@@ -19,20 +22,32 @@ impl Write {
         Self {
             entries: vec![entry],
             filter: None,
+            delayed: Vec::new(),
         }
     }
 
-    pub fn filter(filter: Filter) -> Self {
+    pub const fn filter(filter: Filter) -> Self {
         Self {
             entries: Vec::new(),
             filter: Some(filter),
+            delayed: Vec::new(),
         }
     }
 
+    /// Also write the entry once the delay has passed, like releasing a button.
+    pub fn then_after(mut self, delay: Duration, entry: Entry) -> Self {
+        self.delayed.push((delay, entry));
+        self
+    }
+
     pub fn entries(&self) -> impl Iterator<Item = &'_ Entry> {
         self.entries.iter()
     }
 
+    pub fn delayed(&self) -> impl Iterator<Item = &'_ (Duration, Entry)> {
+        self.delayed.iter()
+    }
+
     pub fn try_filter(&self) -> Option<Filter> {
         self.filter.clone()
     }
@@ -40,21 +55,27 @@ impl Write {
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Key {
-    inner: String,
+    inner: SmolStr,
 }
 
+/// For keys known ahead of time. Panics if the key contains a `/`, so anything read from the
+/// user or the network should go through `Key::from_utf8` instead.
 impl From<&str> for Key {
     fn from(value: &str) -> Self {
-        Self {
-            inner: value.into(),
-        }
+        Self::from_utf8(value.as_bytes()).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
 impl Key {
-    pub fn from_utf8(bytes: &[u8]) -> Result<Self, Utf8Error> {
-        let inner = String::from(from_utf8(bytes)?);
-        Ok(Self { inner })
+    /// Create a key from raw bytes, checking that they form a single path component.
+    pub fn from_utf8(bytes: &[u8]) -> Result<Self, PathError> {
+        let inner = from_utf8(bytes).map_err(PathError::InvalidUtf8)?;
+        if inner.contains('/') {
+            return Err(PathError::Separator(inner.to_string()));
+        }
+        Ok(Self {
+            inner: inner.into(),
+        })
     }
 
     pub fn as_str(&self) -> &str {
@@ -78,6 +99,8 @@ impl fmt::Display for Key {
 pub struct Path {
     pub first: Key,
     pub rest: Vec<Key>,
+    /// Topics almost always start with a `/`, but some, like the `$sys` meta topics, don't.
+    pub leading_slash: bool,
 }
 
 impl Path {
@@ -89,11 +112,31 @@ impl Path {
         self.rest.insert(0, self.first);
         self.rest
     }
+
+    /// The path without its last key, with the same leading slash.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, rest) = self.rest.split_last()?;
+        Some(Self {
+            first: self.first.clone(),
+            rest: rest.to_vec(),
+            leading_slash: self.leading_slash,
+        })
+    }
 }
 
 impl fmt::Debug for Path {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "/{}", self.first)?;
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Formats the path as the exact NT topic name it was parsed from.
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.leading_slash {
+            f.write_str("/")?;
+        }
+        write!(f, "{}", self.first)?;
 
         for key in &self.rest {
             write!(f, "/{key}")?;
@@ -115,7 +158,11 @@ impl TryFrom<Vec<Key>> for Path {
             Err(Empty)
         } else {
             let first = value.remove(0);
-            Ok(Self { first, rest: value })
+            Ok(Self {
+                first,
+                rest: value,
+                leading_slash: true,
+            })
         }
     }
 }
@@ -124,36 +171,31 @@ impl TryFrom<Vec<Key>> for Path {
 pub enum PathError {
     #[error("Path is empty")]
     Empty,
-    #[error("Path {0} doesn't start with a /")]
-    ExpectedSlash(String),
+    #[error("Key {0:?} contains a /")]
+    Separator(String),
+    #[error("Key isn't valid utf8: {0}")]
+    InvalidUtf8(Utf8Error),
 }
 
+/// Parses an NT topic name. Every `/` after a leading one separates two keys, so empty keys,
+/// whitespace and unicode are all kept exactly as written.
 impl FromStr for Path {
     type Err = PathError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut options = Vec::new();
-
-        let mut buf = Vec::new();
-
-        let mut needs_slash = true;
-
-        for char in s.chars() {
-            if needs_slash {
-                if char != '/' {
-                    return Err(PathError::ExpectedSlash(s.to_string()));
-                }
-                needs_slash = false;
-            } else if char == '/' {
-                options.push(Key::from_utf8(&buf).unwrap());
-                buf.clear();
-            } else {
-                buf.push(char as u8);
-            }
+        if s.is_empty() {
+            return Err(PathError::Empty);
         }
-        options.push(Key::from_utf8(&buf).unwrap());
+        let (rest, leading_slash) = s.strip_prefix('/').map_or((s, false), |rest| (rest, true));
+
+        let mut keys = rest.split('/').map(Key::from);
+        let first = keys.next().ok_or(PathError::Empty)?;
 
-        options.try_into().map_err(|_| PathError::Empty)
+        Ok(Self {
+            first,
+            rest: keys.collect(),
+            leading_slash,
+        })
     }
 }
 
@@ -175,7 +217,7 @@ pub struct Status {
 }
 
 impl Status {
-    pub fn update(&mut self, update: StatusUpdate) {
+    pub const fn update(&mut self, update: StatusUpdate) {
         match update {
             StatusUpdate::IsConnectedChange(is_connected) => self.is_connected = is_connected,
         }
@@ -191,4 +233,122 @@ pub trait Backend {
     fn update(&mut self) -> Update;
     fn write(&mut self, entries: Vec<Entry>);
     fn status(&self) -> Status;
+
+    /// Why a write to the path would be dropped, checked before showing an entry that the backend
+    /// never publishes.
+    fn check_write(&self, _path: &Path) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// The status of each named connection, for backends that merge several connections.
+    fn connections(&self) -> Vec<(Key, Status)> {
+        Vec::new()
+    }
+}
+
+impl<B: Backend + ?Sized> Backend for Box<B> {
+    fn update(&mut self) -> Update {
+        self.as_mut().update()
+    }
+
+    fn write(&mut self, entries: Vec<Entry>) {
+        self.as_mut().write(entries);
+    }
+
+    fn status(&self) -> Status {
+        self.as_ref().status()
+    }
+
+    fn check_write(&self, path: &Path) -> anyhow::Result<()> {
+        self.as_ref().check_write(path)
+    }
+
+    fn connections(&self) -> Vec<(Key, Status)> {
+        self.as_ref().connections()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Key, Path, PathError};
+
+    #[test]
+    fn topics_round_trip() {
+        for topic in [
+            "/SmartDashboard/arm current",
+            "/SmartDashboard/Motor #1/.type",
+            "/",
+            "//",
+            "/a//b/",
+            "/ümlaut/日本語/🤖",
+            "/ leading and trailing spaces ",
+            "/../.",
+            "$sys/clients",
+            "no slash",
+        ] {
+            let path: Path = topic.parse().unwrap();
+            assert_eq!(path.to_string(), topic);
+        }
+    }
+
+    #[test]
+    fn keeps_empty_keys() {
+        let path: Path = "/a//b/".parse().unwrap();
+        assert_eq!(path.first.as_str(), "a");
+        let rest: Vec<_> = path.rest.iter().map(Key::as_str).collect();
+        assert_eq!(rest, ["", "b", ""]);
+        assert!(path.leading_slash);
+    }
+
+    #[test]
+    fn leading_slash_is_part_of_the_topic() {
+        let with: Path = "/$sys/clients".parse().unwrap();
+        let without: Path = "$sys/clients".parse().unwrap();
+        assert!(!without.leading_slash);
+        assert_ne!(with, without);
+    }
+
+    #[test]
+    fn rejects_empty_paths() {
+        assert!(matches!("".parse::<Path>(), Err(PathError::Empty)));
+    }
+
+    #[test]
+    fn keys_reject_separators_and_invalid_utf8() {
+        assert!(matches!(
+            Key::from_utf8(b"a/b"),
+            Err(PathError::Separator(_))
+        ));
+        assert!(matches!(
+            Key::from_utf8(&[0x61, 0xff]),
+            Err(PathError::InvalidUtf8(_))
+        ));
+        assert_eq!(Key::from_utf8("日本".as_bytes()).unwrap().as_str(), "日本");
+    }
+
+    #[test]
+    #[should_panic(expected = "contains a /")]
+    fn from_str_rejects_separators() {
+        let _ = Key::from("a/b");
+    }
+
+    #[test]
+    fn keys_round_trip_through_paths() {
+        for leading_slash in [true, false] {
+            let mut path =
+                Path::try_from(vec![Key::from("Smart Dashboard"), Key::from("")]).unwrap();
+            path.leading_slash = leading_slash;
+            let parsed: Path = path.to_string().parse().unwrap();
+            assert_eq!(parsed, path);
+        }
+    }
+
+    #[test]
+    fn parents_keep_the_leading_slash() {
+        let path: Path = "$sys/clients/1".parse().unwrap();
+        let parent = path.parent().unwrap();
+        assert_eq!(parent.to_string(), "$sys/clients");
+        assert_eq!(parent.parent().unwrap().to_string(), "$sys");
+        assert!(parent.parent().unwrap().parent().is_none());
+    }
 }
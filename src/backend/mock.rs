@@ -57,6 +57,7 @@ pub fn example_dashboard() -> TMap {
         "auto".into() => auto,
         "tabs".into() => tabs
     };
+    smartdashboard_map.extend(sendables());
     for name in [
         "left encoder",
         "right encoder",
@@ -78,8 +79,188 @@ pub fn example_dashboard() -> TMap {
 
     let smartdashboard: T = Box::new(smartdashboard_map);
     map! {
-        "Smartdashboard".into() => smartdashboard
+        "Smartdashboard".into() => smartdashboard,
+        "FMSInfo".into() => fms_info()
+    }
+}
+
+fn fms_info() -> T {
+    let integer = |value: u64| -> T { Box::new(Value::Integer(Integer::from(value))) };
+    Box::new(map! {
+        ".type".into() => Box::new(Value::String("FMSInfo".into())) as T,
+        "EventName".into() => Box::new(Value::String("CASJ".into())) as T,
+        "MatchType".into() => integer(2),
+        "MatchNumber".into() => integer(12),
+        "StationNumber".into() => integer(2),
+        "IsRedAlliance".into() => Box::new(Value::Boolean(true)) as T,
+        // enabled in autonomous with the FMS and driver station attached
+        "FMSControlData".into() => integer(0x33)
+    })
+}
+
+// the typed sendables WPILib publishes, one of each that has a widget
+fn sendables() -> TMap {
+    map! {
+        "field".into() => field(),
+        "arm mechanism".into() => mechanism(),
+        "arm pid".into() => numeric_sendable(
+            "PIDController",
+            &[
+                ("p", 0.1),
+                ("i", 0.0),
+                ("d", 0.01),
+                ("setpoint", 90.0),
+                ("izone", f64::INFINITY),
+            ],
+        ),
+        "gyro".into() => numeric_sendable("Gyro", &[("Value", 0.0)]),
+        "intake motor".into() => motor_controller(),
+        "claw".into() => solenoid(),
+        "pdh".into() => numeric_sendable(
+            "PowerDistribution",
+            &[
+                ("Chan0", 12.0),
+                ("Chan1", 3.5),
+                ("Chan2", 0.0),
+                ("Chan3", 25.0),
+                ("Voltage", 12.3),
+                ("TotalCurrent", 40.5),
+                ("Temperature", 31.0),
+            ],
+        ),
+        "drive".into() => numeric_sendable(
+            "DifferentialDrive",
+            &[("Left Motor Speed", 0.0), ("Right Motor Speed", 0.0)],
+        ),
+        "Alerts".into() => alerts(),
+        "intake command".into() => command(),
+        "Scheduler".into() => scheduler(),
+        "drivetrain subsystem".into() => subsystem()
+    }
+}
+
+fn field() -> T {
+    let field_type: T = Box::new(Value::String("Field2d".into()));
+    let robot: T = Box::new(Value::Array(vec![
+        Value::F64(3.0),
+        Value::F64(4.0),
+        Value::F64(30.0),
+    ]));
+    let trajectory: T = Box::new(Value::Array(
+        [(3.0, 4.0), (6.0, 5.5), (10.0, 5.0), (13.0, 2.0)]
+            .into_iter()
+            .flat_map(|(x, y)| [Value::F64(x), Value::F64(y), Value::F64(0.0)])
+            .collect(),
+    ));
+    Box::new(map! {
+        ".type".into() => field_type,
+        "Robot".into() => robot,
+        "Trajectory".into() => trajectory
+    })
+}
+
+fn mechanism() -> T {
+    let ligament = |angle: f64, length: f64, color: &str, ligaments: TMap| -> T {
+        let mut ligament: TMap = map! {
+            ".type".into() => Box::new(Value::String("line".into())) as T,
+            "angle".into() => Box::new(Value::F64(angle)) as T,
+            "length".into() => Box::new(Value::F64(length)) as T,
+            "color".into() => Box::new(Value::String(color.into())) as T,
+            "weight".into() => Box::new(Value::F64(10.0)) as T
+        };
+        ligament.extend(ligaments);
+        Box::new(ligament)
+    };
+    let wrist = ligament(-60.0, 0.4, "#FF00FF", TMap::new());
+    let arm = ligament(45.0, 1.0, "#FFFF00", map! { "wrist".into() => wrist });
+    let pivot: T = Box::new(map! {
+        "x".into() => Box::new(Value::F64(1.0)) as T,
+        "y".into() => Box::new(Value::F64(0.5)) as T,
+        "arm".into() => arm
+    });
+    Box::new(map! {
+        ".type".into() => Box::new(Value::String("Mechanism2d".into())) as T,
+        "dims".into() => Box::new(Value::Array(vec![Value::F64(3.0), Value::F64(3.0)])) as T,
+        "backgroundColor".into() => Box::new(Value::String("#000020".into())) as T,
+        "pivot".into() => pivot
+    })
+}
+
+// a sendable whose fields are all doubles
+fn numeric_sendable(r#type: &str, fields: &[(&str, f64)]) -> T {
+    let mut sendable: TMap = map! {
+        ".type".into() => Box::new(Value::String(r#type.into())) as T
+    };
+    for (name, value) in fields {
+        sendable.insert((*name).into(), Box::new(Value::F64(*value)));
     }
+    Box::new(sendable)
+}
+
+fn motor_controller() -> T {
+    Box::new(map! {
+        ".type".into() => Box::new(Value::String("Motor Controller".into())) as T,
+        ".controllable".into() => Box::new(Value::Boolean(true)) as T,
+        "Value".into() => Box::new(Value::F64(0.0)) as T
+    })
+}
+
+fn solenoid() -> T {
+    Box::new(map! {
+        ".type".into() => Box::new(Value::String("Double Solenoid".into())) as T,
+        ".controllable".into() => Box::new(Value::Boolean(true)) as T,
+        "Value".into() => Box::new(Value::String("Off".into())) as T
+    })
+}
+
+fn alerts() -> T {
+    let strings = |texts: &[&str]| -> T {
+        Box::new(Value::Array(
+            texts
+                .iter()
+                .map(|text| Value::String((*text).into()))
+                .collect(),
+        ))
+    };
+    Box::new(map! {
+        ".type".into() => Box::new(Value::String("Alerts".into())) as T,
+        "errors".into() => strings(&["Camera disconnected"]),
+        "warnings".into() => strings(&["Battery below 11 V", "Arm not homed"]),
+        "infos".into() => strings(&["Auto selected: Left"])
+    })
+}
+
+fn command() -> T {
+    Box::new(map! {
+        ".type".into() => Box::new(Value::String("Command".into())) as T,
+        ".name".into() => Box::new(Value::String("RunIntake".into())) as T,
+        ".isParented".into() => Box::new(Value::Boolean(false)) as T,
+        "running".into() => Box::new(Value::Boolean(false)) as T
+    })
+}
+
+fn scheduler() -> T {
+    let names = ["DefaultDrive", "HoldArm", "RunIntake"];
+    Box::new(map! {
+        ".type".into() => Box::new(Value::String("Scheduler".into())) as T,
+        "Names".into() => Box::new(Value::Array(
+            names.iter().map(|name| Value::String((*name).into())).collect(),
+        )) as T,
+        "Ids".into() => Box::new(Value::Array(
+            (0..names.len()).map(|id| Value::Integer(Integer::from(id * 7))).collect(),
+        )) as T,
+        "Cancel".into() => Box::new(Value::Array(Vec::new())) as T
+    })
+}
+
+fn subsystem() -> T {
+    Box::new(map! {
+        ".type".into() => Box::new(Value::String("Subsystem".into())) as T,
+        ".hasDefault".into() => Box::new(Value::Boolean(true)) as T,
+        ".default".into() => Box::new(Value::String("DefaultDrive".into())) as T,
+        ".hasCommand".into() => Box::new(Value::Boolean(true)) as T,
+        ".command".into() => Box::new(Value::String("AutoAlign".into())) as T
+    })
 }
 
 fn rand_string() -> Value {
@@ -141,6 +322,7 @@ impl Backend for HashMap<Key, Box<dyn Tree>> {
             let update = value.update(Path {
                 first: key.clone(),
                 rest: Vec::new(),
+                leading_slash: true,
             });
             to_update.extend(update.to_update);
             to_create.extend(update.to_create);
@@ -165,6 +347,7 @@ impl Backend for HashMap<Key, Box<dyn Tree>> {
 pub trait Tree: Debug {
     fn update(&mut self, path: Path) -> Update;
     fn write(&mut self, path: Vec<Key>, value: Value);
+    fn is_branch(&self) -> bool;
 }
 
 impl Tree for HashMap<Key, Box<dyn Tree>> {
@@ -188,10 +371,11 @@ impl Tree for HashMap<Key, Box<dyn Tree>> {
         } else {
             let key = path.remove(0);
 
-            if let Some(existing) = self.get_mut(&key) {
-                existing.write(path, value);
-            } else if path.is_empty() {
+            // writes replace whatever was there before, even if it was a different shape
+            if path.is_empty() {
                 self.insert(key, Box::new(value));
+            } else if let Some(existing) = self.get_mut(&key).filter(|tree| tree.is_branch()) {
+                existing.write(path, value);
             } else {
                 let mut map = HashMap::new();
                 Tree::write(&mut map, path, value);
@@ -199,6 +383,10 @@ impl Tree for HashMap<Key, Box<dyn Tree>> {
             }
         }
     }
+
+    fn is_branch(&self) -> bool {
+        true
+    }
 }
 
 static START: Mutex<Option<Instant>> = Mutex::new(None);
@@ -271,4 +459,8 @@ impl Tree for Value {
             panic!("trying to write {path:?} to plain value");
         }
     }
+
+    fn is_branch(&self) -> bool {
+        false
+    }
 }
@@ -1,6 +1,9 @@
 mod backend;
 pub mod mock;
+pub mod multi;
 pub mod nt;
 mod nt_worker;
+mod pattern;
 
 pub use backend::{Backend, Entry, Key, Path, PathError, Status, StatusUpdate, Update, Write};
+pub use pattern::{Pattern, PatternError};
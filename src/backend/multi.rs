@@ -0,0 +1,153 @@
+use std::{
+    net::{Ipv4Addr, SocketAddrV4},
+    str::FromStr,
+};
+
+use thiserror::Error;
+use tracing::{event, Level};
+
+use super::{nt, Backend, Entry, Key, Path, PathError, Status, Update};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("connection {0:?} should look like name=address[:port]")]
+    Malformed(String),
+    #[error("{0}")]
+    Name(PathError),
+    #[error("{0:?} isn't an IPv4 address")]
+    InvalidAddress(String),
+    #[error("{0:?} isn't a port")]
+    InvalidPort(String),
+    #[error("{0} isn't under the name of any connection")]
+    NoConnection(Path),
+    #[error("{0} is a connection rather than a topic")]
+    ConnectionRoot(Path),
+}
+
+/// A connection given on the command line, like `robot=10.12.34.2` or `sim=127.0.0.1:5811`.
+pub struct ConnectionArg {
+    pub root: Key,
+    pub address: SocketAddrV4,
+}
+
+impl FromStr for ConnectionArg {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, address) = s
+            .split_once('=')
+            .ok_or_else(|| Error::Malformed(s.to_string()))?;
+        let root = Key::from_utf8(name.as_bytes()).map_err(Error::Name)?;
+        let (ip, port) = match address.split_once(':') {
+            Some((ip, port)) => (
+                ip,
+                port.parse()
+                    .map_err(|_| Error::InvalidPort(port.to_string()))?,
+            ),
+            None => (address, nt::PORT),
+        };
+        let ip: Ipv4Addr = ip
+            .parse()
+            .map_err(|_| Error::InvalidAddress(ip.to_string()))?;
+        Ok(Self {
+            root,
+            address: SocketAddrV4::new(ip, port),
+        })
+    }
+}
+
+struct Connection {
+    root: Key,
+    backend: Box<dyn Backend>,
+}
+
+/// Merges several backends into one, namespacing the paths of each under its own root key.
+/// A namespaced path keeps the leading slash of the topic it came from, so `/$sys/clients` and
+/// `$sys/clients` stay apart as `/robot/$sys/clients` and `robot/$sys/clients`.
+#[derive(Default)]
+pub struct Multi {
+    connections: Vec<Connection>,
+}
+
+impl Multi {
+    pub fn with(mut self, root: impl Into<Key>, backend: impl Backend + 'static) -> Self {
+        self.connections.push(Connection {
+            root: root.into(),
+            backend: Box::new(backend),
+        });
+        self
+    }
+
+    // the index of the connection the path is under, and the path within it
+    fn route(&self, path: &Path) -> Result<(usize, Path), Error> {
+        let index = self
+            .connections
+            .iter()
+            .position(|connection| connection.root == path.first)
+            .ok_or_else(|| Error::NoConnection(path.clone()))?;
+        let mut inner =
+            Path::try_from(path.rest.clone()).map_err(|_| Error::ConnectionRoot(path.clone()))?;
+        inner.leading_slash = path.leading_slash;
+        Ok((index, inner))
+    }
+}
+
+fn namespace(root: &Key, entries: Vec<Entry>) -> impl Iterator<Item = Entry> + '_ {
+    entries.into_iter().map(|entry| Entry {
+        path: Path {
+            first: root.clone(),
+            leading_slash: entry.path.leading_slash,
+            rest: entry.path.into_vec(),
+        },
+        value: entry.value,
+    })
+}
+
+impl Backend for Multi {
+    fn update(&mut self) -> Update {
+        let mut to_update = Vec::new();
+        let mut to_create = Vec::new();
+        for connection in &mut self.connections {
+            let update = connection.backend.update();
+            to_update.extend(namespace(&connection.root, update.to_update));
+            to_create.extend(namespace(&connection.root, update.to_create));
+        }
+        Update {
+            to_update,
+            to_create,
+        }
+    }
+
+    fn write(&mut self, entries: Vec<Entry>) {
+        for entry in entries {
+            match self.route(&entry.path) {
+                Ok((index, path)) => self.connections[index].backend.write(vec![Entry {
+                    path,
+                    value: entry.value,
+                }]),
+                Err(error) => event!(Level::WARN, "dropping a write: {error}"),
+            }
+        }
+    }
+
+    fn check_write(&self, path: &Path) -> anyhow::Result<()> {
+        let (index, path) = self.route(path)?;
+        self.connections[index].backend.check_write(&path)
+    }
+
+    fn status(&self) -> Status {
+        Status {
+            is_connected: self
+                .connections
+                .iter()
+                .all(|connection| connection.backend.status().is_connected),
+        }
+    }
+
+    fn connections(&self) -> Vec<(Key, Status)> {
+        self.connections
+            .iter()
+            .map(|connection| (connection.root.clone(), connection.backend.status()))
+            .collect()
+    }
+}
@@ -1,23 +1,20 @@
 #![allow(dead_code)]
 
-use core::fmt;
 use std::{
     collections::HashSet,
-    os::unix::prelude::OsStrExt,
-    path::{Component, PathBuf},
+    net::{Ipv4Addr, SocketAddrV4},
 };
 
-use thiserror::Error;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
 
-
-
 use super::{
     backend::{Entry, Path, Status, StatusUpdate, Update},
-    nt_worker::{run_worker},
-    Backend, Key,
+    nt_worker::run_worker,
+    Backend,
 };
-use anyhow::Result;
+
+/// The port NT4 servers listen on.
+pub const PORT: u16 = 5810;
 
 pub struct Nt {
     read_receiver: UnboundedReceiver<Entry>,
@@ -66,11 +63,17 @@ impl Backend for Nt {
 
 impl Nt {
     pub fn new() -> Self {
+        Self::with_address(SocketAddrV4::new(Ipv4Addr::LOCALHOST, PORT))
+    }
+
+    pub fn with_address(address: SocketAddrV4) -> Self {
         let (read_sender, read_receiver) = unbounded_channel();
         let (write_sender, write_receiver) = unbounded_channel();
         let (status_sender, status_receiver) = unbounded_channel();
 
-        tokio::spawn(async move { run_worker(read_sender, write_receiver, status_sender).await });
+        tokio::spawn(async move {
+            run_worker(read_sender, write_receiver, status_sender, address).await
+        });
 
         Self {
             read_receiver,
@@ -103,38 +106,3 @@ impl Nt {
         self.write_sender.send(entry).unwrap();
     }
 }
-
-#[derive(Debug, Error)]
-pub enum KeyError {
-    #[error("Paths must have at least 1 component")]
-    Empty,
-}
-
-pub fn from_nt_path(path: String) -> Result<Path, KeyError> {
-    let buf = PathBuf::from(path);
-    let mut vec: Vec<Key> = buf
-        .components()
-        .filter_map(|comp| {
-            if let Component::Normal(str) = comp {
-                Some(Key::from_utf8(str.as_bytes()).unwrap())
-            } else {
-                None
-            }
-        })
-        .collect();
-    if vec.is_empty() {
-        return Err(KeyError::Empty);
-    }
-    let first = vec.remove(0);
-    Ok(Path { first, rest: vec })
-}
-
-impl fmt::Display for Path {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "/{}", self.first)?;
-        for comp in &self.rest {
-            write!(f, "/{comp}")?;
-        }
-        Ok(())
-    }
-}
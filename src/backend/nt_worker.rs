@@ -1,10 +1,6 @@
 #![allow(dead_code)]
 
-use std::{
-    collections::HashMap,
-    net::{Ipv4Addr, SocketAddrV4},
-    time::Duration,
-};
+use std::{collections::HashMap, net::SocketAddrV4, time::Duration};
 
 use network_tables::{
     rmpv::ValueRef,
@@ -18,7 +14,6 @@ use tokio::{
 use anyhow::Result;
 use tracing::{event, Level};
 
-use super::nt::from_nt_path;
 use super::{Entry, Path, StatusUpdate};
 
 pub struct Worker {
@@ -32,6 +27,7 @@ pub struct SubscribedClient {
     subscription: Subscription,
     published_topics: HashMap<Path, PublishedTopic>,
     status_sender: UnboundedSender<StatusUpdate>,
+    address: SocketAddrV4,
 }
 
 impl SubscribedClient {
@@ -65,18 +61,33 @@ impl SubscribedClient {
     async fn read(&mut self) -> Result<Entry> {
         loop {
             if let Some(message) = self.subscription.next().await {
-                return Ok(Entry {
-                    path: from_nt_path(message.topic_name).map_err(Into::<anyhow::Error>::into)?,
-                    value: message.data,
-                });
+                match message.topic_name.parse() {
+                    Ok(path) => {
+                        return Ok(Entry {
+                            path,
+                            value: message.data,
+                        })
+                    }
+                    Err(error) => {
+                        event!(
+                            Level::WARN,
+                            "skipping topic {:?}: {error}",
+                            message.topic_name
+                        );
+                        continue;
+                    }
+                }
             }
-            *self = Self::new(self.status_sender.clone()).await?;
+            *self = Self::new(self.status_sender.clone(), self.address).await?;
         }
     }
 
-    async fn new(status_sender: UnboundedSender<StatusUpdate>) -> Result<Self> {
+    async fn new(
+        status_sender: UnboundedSender<StatusUpdate>,
+        address: SocketAddrV4,
+    ) -> Result<Self> {
         status_sender.send(StatusUpdate::IsConnectedChange(false))?;
-        let client = connect_to_client().await;
+        let client = connect_to_client(address).await;
         let subscription = subscribe(&client).await?;
         status_sender.send(StatusUpdate::IsConnectedChange(true))?;
         Ok(Self {
@@ -84,6 +95,7 @@ impl SubscribedClient {
             subscription,
             published_topics: HashMap::new(),
             status_sender,
+            address,
         })
     }
 }
@@ -93,10 +105,11 @@ impl Worker {
         read_sender: UnboundedSender<Entry>,
         write_receiver: UnboundedReceiver<Entry>,
         status_sender: UnboundedSender<StatusUpdate>,
+        address: SocketAddrV4,
     ) -> Self {
         let client;
         loop {
-            let maybe_client = SubscribedClient::new(status_sender.clone()).await;
+            let maybe_client = SubscribedClient::new(status_sender.clone(), address).await;
             match maybe_client {
                 Ok(c) => {
                     client = c;
@@ -125,10 +138,14 @@ impl Worker {
                     }
                 },
                 to_read = self.client.read() => {
-                    if let Ok(entry) = to_read {
-
-                        if let Err(error) = self.read_sender.send(entry) {
-                            event!(Level::ERROR, "pipe error while sending entry to main nt thread: {error:?}");
+                    match to_read {
+                        Ok(entry) => {
+                            if let Err(error) = self.read_sender.send(entry) {
+                                event!(Level::ERROR, "pipe error while sending entry to main nt thread: {error:?}");
+                            }
+                        }
+                        Err(error) => {
+                            event!(Level::ERROR, "error while reading from subscribed client: {error}");
                         }
                     }
                 }
@@ -137,13 +154,10 @@ impl Worker {
     }
 }
 
-async fn connect_to_client() -> Client {
+async fn connect_to_client(address: SocketAddrV4) -> Client {
     loop {
-        let maybe_client = network_tables::v4::Client::try_new_w_config(
-            SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 5810),
-            Config::default(),
-        )
-        .await;
+        let maybe_client =
+            network_tables::v4::Client::try_new_w_config(address, Config::default()).await;
         if let Ok(c) = maybe_client {
             return c;
         }
@@ -154,7 +168,7 @@ async fn connect_to_client() -> Client {
 async fn subscribe(client: &Client) -> Result<Subscription> {
     client
         .subscribe_w_options(
-            &["/SmartDashboard"],
+            &["/SmartDashboard", "/LiveWindow", "/FMSInfo"],
             Some(SubscriptionOptions {
                 all: Some(true),
                 prefix: Some(true),
@@ -197,8 +211,9 @@ pub async fn run_worker(
     read_sender: UnboundedSender<Entry>,
     write_receiver: UnboundedReceiver<Entry>,
     status_sender: UnboundedSender<StatusUpdate>,
+    address: SocketAddrV4,
 ) -> Result<()> {
-    let worker = Worker::new(read_sender, write_receiver, status_sender).await;
+    let worker = Worker::new(read_sender, write_receiver, status_sender, address).await;
     worker.run().await;
     Ok(())
 }
@@ -0,0 +1,387 @@
+use std::{borrow::Borrow, fmt, iter, str::FromStr};
+
+use thiserror::Error;
+
+use super::{Key, Path};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum Token {
+    Literal(char),
+    /// `*`, any number of characters within a key
+    Star,
+    /// `?`, exactly one character
+    Question,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Segment {
+    /// `**`, any number of keys
+    Keys,
+    /// a single key matching any of the alternatives
+    Key(Vec<Vec<Token>>),
+}
+
+/// A pattern over paths, written like a path where each key may use `*` and `?` wildcards and
+/// `{a,b}` alternation, and a key of `**` matches any number of keys.
+/// Special characters are escaped with `\`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    source: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Error)]
+pub enum PatternError {
+    #[error("Pattern is empty")]
+    Empty,
+    #[error("Pattern {0} doesn't start with a /")]
+    ExpectedSlash(String),
+    #[error("Pattern {0} ends with an unfinished escape")]
+    TrailingEscape(String),
+    #[error("Pattern {0} has an unclosed {{")]
+    UnclosedBrace(String),
+    #[error("Pattern {0} has an unmatched }}")]
+    UnmatchedBrace(String),
+    #[error("Pattern {0} nests {{ inside of {{")]
+    NestedBrace(String),
+}
+
+struct SegmentParser {
+    alternatives: Vec<Vec<Token>>,
+    group: Option<Vec<Vec<Token>>>,
+}
+
+impl SegmentParser {
+    fn new() -> Self {
+        Self {
+            alternatives: vec![Vec::new()],
+            group: None,
+        }
+    }
+
+    fn push(&mut self, token: Token) {
+        if let Some(group) = &mut self.group {
+            if let Some(option) = group.last_mut() {
+                option.push(token);
+            }
+        } else {
+            for alternative in &mut self.alternatives {
+                alternative.push(token);
+            }
+        }
+    }
+
+    fn open(&mut self, source: &str) -> Result<(), PatternError> {
+        if self.group.is_some() {
+            return Err(PatternError::NestedBrace(source.to_string()));
+        }
+        self.group = Some(vec![Vec::new()]);
+        Ok(())
+    }
+
+    fn next_option(&mut self) -> bool {
+        let Some(group) = &mut self.group else {
+            return false;
+        };
+        group.push(Vec::new());
+        true
+    }
+
+    fn close(&mut self, source: &str) -> Result<(), PatternError> {
+        let group = self
+            .group
+            .take()
+            .ok_or_else(|| PatternError::UnmatchedBrace(source.to_string()))?;
+        self.alternatives = self
+            .alternatives
+            .iter()
+            .flat_map(|prefix| {
+                group.iter().map(move |option| {
+                    let mut alternative = prefix.clone();
+                    alternative.extend(option.iter().copied());
+                    alternative
+                })
+            })
+            .collect();
+        Ok(())
+    }
+
+    fn finish(&mut self, source: &str) -> Result<Segment, PatternError> {
+        if self.group.is_some() {
+            return Err(PatternError::UnclosedBrace(source.to_string()));
+        }
+        let finished = std::mem::replace(self, Self::new());
+        if finished.alternatives == [vec![Token::Star, Token::Star]] {
+            Ok(Segment::Keys)
+        } else {
+            Ok(Segment::Key(finished.alternatives))
+        }
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        let rest = s
+            .strip_prefix('/')
+            .ok_or_else(|| PatternError::ExpectedSlash(s.to_string()))?;
+
+        let mut segments = Vec::new();
+        let mut parser = SegmentParser::new();
+        let mut chars = rest.chars();
+
+        while let Some(char) = chars.next() {
+            match char {
+                '\\' => {
+                    let escaped = chars
+                        .next()
+                        .ok_or_else(|| PatternError::TrailingEscape(s.to_string()))?;
+                    parser.push(Token::Literal(escaped));
+                }
+                '/' => segments.push(parser.finish(s)?),
+                '*' => parser.push(Token::Star),
+                '?' => parser.push(Token::Question),
+                '{' => parser.open(s)?,
+                '}' => parser.close(s)?,
+                ',' => {
+                    if !parser.next_option() {
+                        parser.push(Token::Literal(','));
+                    }
+                }
+                char => parser.push(Token::Literal(char)),
+            }
+        }
+        segments.push(parser.finish(s)?);
+
+        Ok(Self {
+            source: s.to_string(),
+            segments,
+        })
+    }
+}
+
+/// Builds a pattern that only matches the given path.
+impl From<Path> for Pattern {
+    fn from(path: Path) -> Self {
+        let mut source = String::new();
+        let mut segments = Vec::new();
+        for key in iter::once(&path.first).chain(&path.rest) {
+            source.push('/');
+            for char in key.as_str().chars() {
+                if matches!(char, '\\' | '*' | '?' | '{' | '}' | ',') {
+                    source.push('\\');
+                }
+                source.push(char);
+            }
+            segments.push(Segment::Key(vec![key
+                .as_str()
+                .chars()
+                .map(Token::Literal)
+                .collect()]));
+        }
+        Self { source, segments }
+    }
+}
+
+fn matches_key(tokens: &[Token], key: &str) -> bool {
+    let chars: Vec<char> = key.chars().collect();
+    let mut token = 0;
+    let mut char = 0;
+    // the most recent star and the position in the key it's currently consuming up to
+    let mut backtrack = None;
+
+    while char < chars.len() {
+        match tokens.get(token) {
+            Some(Token::Star) => {
+                backtrack = Some((token, char));
+                token += 1;
+            }
+            Some(Token::Question) => {
+                token += 1;
+                char += 1;
+            }
+            Some(Token::Literal(literal)) if *literal == chars[char] => {
+                token += 1;
+                char += 1;
+            }
+            _ => {
+                let Some((star, consumed)) = backtrack else {
+                    return false;
+                };
+                backtrack = Some((star, consumed + 1));
+                token = star + 1;
+                char = consumed + 1;
+            }
+        }
+    }
+
+    tokens[token..].iter().all(|token| *token == Token::Star)
+}
+
+fn matches_keys<K: Borrow<Key>>(segments: &[Segment], keys: &[K]) -> bool {
+    // whether the segments so far match the first n keys, for every n, which keeps patterns with
+    // several `**` linear instead of trying every way of splitting the keys between them
+    let mut matched = vec![false; keys.len() + 1];
+    matched[0] = true;
+    for segment in segments {
+        match segment {
+            Segment::Keys => {
+                if let Some(first) = matched.iter().position(|matched| *matched) {
+                    matched[first..].fill(true);
+                }
+            }
+            Segment::Key(alternatives) => {
+                // backwards so each key is only matched against the prefix before it
+                for (index, key) in keys.iter().enumerate().rev() {
+                    matched[index + 1] = matched[index]
+                        && alternatives
+                            .iter()
+                            .any(|tokens| matches_key(tokens, key.borrow().as_str()));
+                }
+                matched[0] = false;
+            }
+        }
+    }
+    matched[keys.len()]
+}
+
+impl Pattern {
+    pub fn matches(&self, path: &Path) -> bool {
+        let keys: Vec<_> = iter::once(&path.first).chain(&path.rest).collect();
+        matches_keys(&self.segments, &keys)
+    }
+
+    pub fn matches_keys(&self, keys: &[Key]) -> bool {
+        matches_keys(&self.segments, keys)
+    }
+}
+
+impl fmt::Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.source.fmt(f)
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.source.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pattern, PatternError};
+    use crate::backend::{Key, Path};
+
+    fn matches(pattern: &str, topic: &str) -> bool {
+        let pattern: Pattern = pattern.parse().unwrap();
+        let path: Path = topic.parse().unwrap();
+        pattern.matches(&path)
+    }
+
+    #[test]
+    fn literals() {
+        assert!(matches("/SmartDashboard/arm", "/SmartDashboard/arm"));
+        assert!(!matches("/SmartDashboard/arm", "/SmartDashboard/arms"));
+        assert!(!matches("/SmartDashboard/arm", "/SmartDashboard"));
+        assert!(!matches("/SmartDashboard", "/SmartDashboard/arm"));
+    }
+
+    #[test]
+    fn star_matches_within_a_key() {
+        assert!(matches("/SmartDashboard/*", "/SmartDashboard/arm"));
+        assert!(matches("/SmartDashboard/*", "/SmartDashboard/"));
+        assert!(matches(
+            "/SmartDashboard/arm *",
+            "/SmartDashboard/arm current"
+        ));
+        assert!(matches("/SmartDashboard/*a*b*", "/SmartDashboard/xaxbx"));
+        assert!(!matches("/SmartDashboard/*", "/SmartDashboard/arm/kP"));
+        assert!(!matches("/SmartDashboard/*b", "/SmartDashboard/abc"));
+    }
+
+    #[test]
+    fn question_matches_one_character() {
+        assert!(matches("/SmartDashboard/k?", "/SmartDashboard/kP"));
+        assert!(matches("/SmartDashboard/?", "/SmartDashboard/日"));
+        assert!(!matches("/SmartDashboard/k?", "/SmartDashboard/k"));
+        assert!(!matches("/SmartDashboard/k?", "/SmartDashboard/kPa"));
+    }
+
+    #[test]
+    fn double_star_matches_any_number_of_keys() {
+        assert!(matches("/**", "/SmartDashboard"));
+        assert!(matches("/SmartDashboard/**", "/SmartDashboard"));
+        assert!(matches("/SmartDashboard/**", "/SmartDashboard/arm/kP"));
+        assert!(matches("/**/kP", "/SmartDashboard/arm/pid/kP"));
+        assert!(matches("/**/arm/**/kP", "/SmartDashboard/arm/pid/kP"));
+        assert!(!matches("/**/kP", "/SmartDashboard/arm/kI"));
+        assert!(!matches("/SmartDashboard/**/kP", "/Shuffleboard/arm/kP"));
+    }
+
+    #[test]
+    fn many_double_stars_stay_fast() {
+        let pattern: Pattern = format!("{}/missing", "/**".repeat(40)).parse().unwrap();
+        let keys: Vec<_> = (0..40)
+            .map(|index| Key::from(index.to_string().as_str()))
+            .collect();
+        assert!(!pattern.matches_keys(&keys));
+    }
+
+    #[test]
+    fn braces_match_any_alternative() {
+        assert!(matches(
+            "/SmartDashboard/{left,right} encoder",
+            "/SmartDashboard/left encoder"
+        ));
+        assert!(matches(
+            "/SmartDashboard/{left,right} encoder",
+            "/SmartDashboard/right encoder"
+        ));
+        assert!(!matches(
+            "/SmartDashboard/{left,right} encoder",
+            "/SmartDashboard/rear encoder"
+        ));
+        assert!(matches("/SmartDashboard/{a,b}{c,d}", "/SmartDashboard/bc"));
+        assert!(matches("/SmartDashboard/x{,y}", "/SmartDashboard/x"));
+        assert!(matches("/SmartDashboard/a,b", "/SmartDashboard/a,b"));
+    }
+
+    #[test]
+    fn backslash_escapes_special_characters() {
+        assert!(matches(r"/SmartDashboard/\*", "/SmartDashboard/*"));
+        assert!(!matches(r"/SmartDashboard/\*", "/SmartDashboard/arm"));
+        assert!(matches(r"/SmartDashboard/\{a,b\}", "/SmartDashboard/{a,b}"));
+        assert!(matches(r"/SmartDashboard/what\?", "/SmartDashboard/what?"));
+        assert!(matches(r"/SmartDashboard/a\\b", r"/SmartDashboard/a\b"));
+    }
+
+    #[test]
+    fn errors() {
+        let error = |pattern: &str| pattern.parse::<Pattern>().unwrap_err();
+        assert!(matches!(error(""), PatternError::Empty));
+        assert!(matches!(
+            error("SmartDashboard"),
+            PatternError::ExpectedSlash(_)
+        ));
+        assert!(matches!(error(r"/arm\"), PatternError::TrailingEscape(_)));
+        assert!(matches!(error("/{a,b"), PatternError::UnclosedBrace(_)));
+        assert!(matches!(error("/a}"), PatternError::UnmatchedBrace(_)));
+        assert!(matches!(error("/{a,{b}}"), PatternError::NestedBrace(_)));
+    }
+
+    #[test]
+    fn paths_match_themselves_exactly() {
+        let path: Path = "/SmartDashboard/{weird}*,key?".parse().unwrap();
+        let pattern = Pattern::from(path.clone());
+        assert!(pattern.matches(&path));
+        assert_eq!(pattern.to_string(), r"/SmartDashboard/\{weird\}\*\,key\?");
+        let reparsed: Pattern = pattern.to_string().parse().unwrap();
+        assert!(reparsed.matches(&path));
+        assert!(!reparsed.matches(&"/SmartDashboard/{weird}x,key?".parse().unwrap()));
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+
+use crate::{
+    backend::{mock, Backend, Entry},
+    state::packing::{GridPosition, Packing},
+    widget_tree::Tree,
+    widgets::{self, Size},
+};
+
+const SIZES: [usize; 4] = [80, 400, 2000, 10000];
+const ITERATIONS: u32 = 20;
+// the grid the app starts with
+const GRID: Size = Size {
+    width: 8,
+    height: 10,
+};
+
+fn time(mut f: impl FnMut() -> Result<()>) -> Result<Duration> {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f()?;
+    }
+    Ok(start.elapsed() / ITERATIONS)
+}
+
+fn bench(widgets: usize) -> Result<()> {
+    let mut network_table = mock::stressing_example(widgets);
+    // the mock backend only reports entries as created for a short while after startup
+    let update = network_table.update();
+    let entries: Vec<Entry> = update
+        .to_create
+        .into_iter()
+        .chain(update.to_update)
+        .collect();
+
+    let create = time(|| {
+        let mut tree = Tree::new(widgets::builders());
+        for entry in &entries {
+            tree.create_entry(entry.clone())?;
+        }
+        black_box(tree);
+        Ok(())
+    })?;
+
+    let mut tree = Tree::new(widgets::builders());
+    for entry in &entries {
+        tree.create_entry(entry.clone())?;
+    }
+
+    let update = time(|| {
+        for entry in &entries {
+            tree.update_entry(entry.clone())?;
+        }
+        Ok(())
+    })?;
+
+    let paths: Vec<_> = tree
+        .widgets()
+        .into_iter()
+        .map(|widget| widget.title.clone())
+        .collect();
+    let get = time(|| {
+        for path in &paths {
+            black_box(tree.get(path));
+        }
+        Ok(())
+    })?;
+
+    // the packing only holds what fits on the grid, so finding the widget under the cursor scans at
+    // most a grid's worth of widgets however many the tree has, and isn't worth an index
+    let mut packing = Packing::new(GRID);
+    packing.add_all(
+        tree.widgets()
+            .into_iter()
+            .map(|widget| (&widget.title, widget.size()))
+            .collect(),
+    );
+    let cells: Vec<_> = (0..GRID.height)
+        .flat_map(|y| (0..GRID.width).map(move |x| GridPosition { x, y }))
+        .collect();
+    let get_path = time(|| {
+        for cell in &cells {
+            black_box(packing.get_path(*cell));
+        }
+        Ok(())
+    })?;
+
+    println!(
+        "{widgets:>6} widgets, {:>6} entries: create {create:>12?}  update {update:>12?}  get {get:>12?}  packing get_path {get_path:>12?}",
+        entries.len()
+    );
+    Ok(())
+}
+
+/// Time the widget tree against `mock::stressing_example` at a range of sizes.
+pub fn run() -> Result<()> {
+    for widgets in SIZES {
+        bench(widgets)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,350 @@
+use std::{collections::HashMap, fs, io, str::FromStr};
+
+use anyhow::Result;
+use thiserror::Error;
+
+use crate::{
+    backend::{Key, Pattern, PatternError},
+    widget_tree::Value,
+    widgets::{self, BuildResult, Builder},
+};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("line {0}: {1}")]
+    AtLine(usize, Box<Self>),
+    #[error("expected `<pattern> = <widget kind> [option=value]...`")]
+    ExpectedEquals,
+    #[error("missing a widget kind")]
+    MissingKind,
+    #[error("{0}")]
+    Pattern(PatternError),
+    #[error("option {0:?} should look like name=value")]
+    MalformedOption(String),
+    #[error("unclosed quote")]
+    UnclosedQuote,
+    #[error("unknown widget kind {0:?}")]
+    UnknownKind(String),
+    #[error("unknown option {0:?}")]
+    UnknownOption(String),
+    #[error("missing option {0}")]
+    MissingOption(String),
+    #[error("option {name}={value:?} is invalid")]
+    InvalidOption { name: String, value: String },
+}
+
+/// The `name=value` options given to a widget kind.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    options: HashMap<String, String>,
+}
+
+impl Options {
+    /// Reject any option that the widget kind doesn't know about, which is usually a typo.
+    pub fn check(&self, known: &[&str]) -> Result<(), Error> {
+        let Some(name) = self
+            .options
+            .keys()
+            .find(|name| !known.contains(&name.as_str()))
+        else {
+            return Ok(());
+        };
+        Err(Error::UnknownOption(name.clone()))
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, Error> {
+        let Some(value) = self.options.get(name) else {
+            return Ok(None);
+        };
+        value.parse().map(Some).map_err(|_| Error::InvalidOption {
+            name: name.to_string(),
+            value: value.clone(),
+        })
+    }
+
+    pub fn get_or<T: FromStr>(&self, name: &str, default: T) -> Result<T, Error> {
+        Ok(self.get(name)?.unwrap_or(default))
+    }
+}
+
+/// Use a particular widget kind for every path matching a pattern.
+pub struct Override {
+    pub line: usize,
+    pub pattern: Pattern,
+    pub kind: String,
+    pub options: Options,
+}
+
+/// Loaded from `smorgasbord.conf`, where each line looks like
+/// `/SmartDashboard/arm current = simple` and `#` at the start of a line or after whitespace starts
+/// a comment. `\` escapes a `#` or `=` that is part of the pattern.
+#[derive(Default)]
+pub struct Config {
+    pub overrides: Vec<Override>,
+}
+
+// splits on whitespace, except inside of double quotes, and unescapes `\#`
+fn split_words(text: &str) -> Result<Vec<String>, Error> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            // other escapes are left for the options, like patterns, that understand them
+            '\\' if chars.peek() == Some(&'#') => {}
+            '"' => in_quotes = !in_quotes,
+            char if char.is_whitespace() && !in_quotes => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            char => word.push(char),
+        }
+    }
+    if in_quotes {
+        return Err(Error::UnclosedQuote);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    Ok(words)
+}
+
+// the byte index of the first `target` that isn't escaped with `\`, and isn't part of a word
+// when `after_whitespace` is set
+fn find_unescaped(text: &str, target: char, after_whitespace: bool) -> Option<usize> {
+    let mut previous = None;
+    let mut chars = text.char_indices();
+    while let Some((index, char)) = chars.next() {
+        if char == '\\' {
+            chars.next();
+        } else if char == target && (!after_whitespace || previous.is_none_or(char::is_whitespace))
+        {
+            return Some(index);
+        }
+        previous = Some(char);
+    }
+    None
+}
+
+fn parse_override(line: usize, text: &str) -> Result<Override, Error> {
+    let equals = find_unescaped(text, '=', false).ok_or(Error::ExpectedEquals)?;
+    let (pattern, widget) = (&text[..equals], &text[equals + 1..]);
+    let pattern = pattern.trim().parse().map_err(Error::Pattern)?;
+    let mut words = split_words(widget)?.into_iter();
+    let kind = words.next().ok_or(Error::MissingKind)?;
+    let options = words
+        .map(|word| match word.split_once('=') {
+            Some((name, value)) => Ok((name.to_string(), value.to_string())),
+            None => Err(Error::MalformedOption(word)),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Override {
+        line,
+        pattern,
+        kind,
+        options: Options { options },
+    })
+}
+
+impl FromStr for Config {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut overrides = Vec::new();
+        for (index, line) in s.lines().enumerate() {
+            let line_text = line.trim_start();
+            let text = find_unescaped(line_text, '#', true)
+                .map_or(line_text, |comment| &line_text[..comment])
+                .trim();
+            if text.is_empty() {
+                continue;
+            }
+            let line = index + 1;
+            let r#override =
+                parse_override(line, text).map_err(|error| Error::AtLine(line, Box::new(error)))?;
+            overrides.push(r#override);
+        }
+        Ok(Self { overrides })
+    }
+}
+
+impl Config {
+    /// Load the config at the path, or the default config if it doesn't exist.
+    pub fn load(path: &str) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(text.parse()?),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Builders for every override, which take priority over the default builders.
+    pub fn builders(&self) -> Result<Vec<Box<dyn Builder>>, Error> {
+        self.overrides
+            .iter()
+            .map(|r#override| {
+                let builder = widgets::configured(&r#override.kind, &r#override.options)
+                    .map_err(|error| Error::AtLine(r#override.line, Box::new(error)))?;
+                Ok(Box::new(OverrideBuilder {
+                    pattern: r#override.pattern.clone(),
+                    builder,
+                }) as Box<dyn Builder>)
+            })
+            .collect()
+    }
+}
+
+struct OverrideBuilder {
+    pattern: Pattern,
+    builder: Box<dyn Builder>,
+}
+
+impl Builder for OverrideBuilder {
+    fn create_kind(&self, key: &Key, value: &Value) -> BuildResult {
+        self.builder.create_kind(key, value)
+    }
+
+    fn priority(&self) -> i32 {
+        i32::MAX
+    }
+
+    fn applies_to(&self, path: &[Key]) -> bool {
+        self.pattern.matches_keys(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, Error};
+    use crate::backend::{Key, Path, PatternError};
+
+    fn parse(text: &str) -> Result<Config, Error> {
+        text.parse()
+    }
+
+    // the error of the only line, without its line number
+    fn error(text: &str) -> Error {
+        match parse(text) {
+            Err(Error::AtLine(1, error)) => *error,
+            Err(error) => panic!("expected an error on line 1, got {error}"),
+            Ok(_) => panic!("expected {text:?} to be rejected"),
+        }
+    }
+
+    fn keys(topic: &str) -> Vec<Key> {
+        topic.parse::<Path>().unwrap().into_vec()
+    }
+
+    #[test]
+    fn parses_patterns_kinds_and_options() {
+        let config = parse("/SmartDashboard/arm k? = number step=0.05\n\n").unwrap();
+        let [r#override] = config.overrides.as_slice() else {
+            panic!("expected one override");
+        };
+        assert_eq!(r#override.line, 1);
+        assert_eq!(r#override.kind, "number");
+        assert!(r#override
+            .pattern
+            .matches_keys(&keys("/SmartDashboard/arm kP")));
+        assert_eq!(r#override.options.get::<f64>("step").unwrap(), Some(0.05));
+    }
+
+    #[test]
+    fn allows_equals_without_spaces_and_quoted_options() {
+        let config = parse(r#"/SmartDashboard/auto=boolean true_label="Auto on""#).unwrap();
+        let r#override = &config.overrides[0];
+        assert_eq!(r#override.kind, "boolean");
+        assert_eq!(
+            r#override.options.get::<String>("true_label").unwrap(),
+            Some(String::from("Auto on"))
+        );
+    }
+
+    #[test]
+    fn unescapes_hashes_and_equals_in_patterns() {
+        let config = parse(r"/SmartDashboard/Motor \#1 \= left = number").unwrap();
+        let r#override = &config.overrides[0];
+        assert_eq!(r#override.kind, "number");
+        assert!(r#override
+            .pattern
+            .matches_keys(&keys("/SmartDashboard/Motor #1 = left")));
+    }
+
+    #[test]
+    fn skips_comments() {
+        let config =
+            parse("# a whole line\n  # an indented one\n/SmartDashboard/a#b = simple # trailing\n")
+                .unwrap();
+        let [r#override] = config.overrides.as_slice() else {
+            panic!("expected one override");
+        };
+        assert_eq!(r#override.line, 3);
+        assert_eq!(r#override.kind, "simple");
+        assert!(r#override
+            .pattern
+            .matches_keys(&keys("/SmartDashboard/a#b")));
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(matches!(error("/SmartDashboard/a"), Error::ExpectedEquals));
+        assert!(matches!(error("/SmartDashboard/a ="), Error::MissingKind));
+        assert!(matches!(
+            error("/SmartDashboard/a = number step"),
+            Error::MalformedOption(_)
+        ));
+        assert!(matches!(
+            error(r#"/SmartDashboard/a = boolean true_label="on"#),
+            Error::UnclosedQuote
+        ));
+        assert!(matches!(
+            error("/SmartDashboard/{a = simple"),
+            Error::Pattern(PatternError::UnclosedBrace(_))
+        ));
+        assert!(matches!(
+            parse("\n/SmartDashboard/a\n"),
+            Err(Error::AtLine(2, _))
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_kinds_and_options() {
+        let builders = |text: &str| parse(text).unwrap().builders().map(|_| ());
+        assert!(matches!(
+            builders("/SmartDashboard/a = sparkline"),
+            Err(Error::AtLine(1, error)) if matches!(*error, Error::UnknownKind(_))
+        ));
+        assert!(matches!(
+            builders("/SmartDashboard/a = number stpe=2"),
+            Err(Error::AtLine(1, error)) if matches!(*error, Error::UnknownOption(_))
+        ));
+        assert!(matches!(
+            builders("/SmartDashboard/a = simple step=2"),
+            Err(Error::AtLine(1, error)) if matches!(*error, Error::UnknownOption(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_option_values() {
+        let builders = |text: &str| parse(text).unwrap().builders().map(|_| ());
+        for text in [
+            "/SmartDashboard/a = number step=0",
+            "/SmartDashboard/a = number step=fast",
+            "/SmartDashboard/a = graph window_s=-1",
+            "/SmartDashboard/a = graph window_s=NaN",
+            "/SmartDashboard/a = graph min=1 max=1",
+            "/SmartDashboard/a = bar min=0 max=NaN",
+            "/SmartDashboard/a = field2d width=0",
+        ] {
+            assert!(
+                matches!(
+                    builders(text),
+                    Err(Error::AtLine(1, error)) if matches!(*error, Error::InvalidOption { .. })
+                ),
+                "{text} was accepted"
+            );
+        }
+    }
+}
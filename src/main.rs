@@ -14,6 +14,8 @@ macro_rules! map(
 );
 
 mod backend;
+mod bench;
+mod config;
 mod state;
 mod view;
 mod widget_tree;
@@ -25,7 +27,13 @@ use std::{
 };
 
 use anyhow::Result;
-use backend::mock::{self};
+use backend::{
+    mock::{self},
+    multi::{ConnectionArg, Multi},
+    nt::Nt,
+    Backend,
+};
+use config::Config;
 use crossterm::{
     event::{self as term_event},
     execute,
@@ -56,14 +64,26 @@ fn shutdown() -> Result<()> {
     Ok(())
 }
 
-fn run() -> Result<()> {
+fn run(config: &Config, connections: Vec<ConnectionArg>) -> Result<()> {
     // ratatui terminal
     let mut t = Terminal::new(CrosstermBackend::new(std::io::stderr()))?;
 
-    //let network_table = Nt::new();
-
-    let network_table = mock::stressing_example(80);
-    //let network_table = mock::example_dashboard();
+    // several connections are namespaced under their names, a single one keeps its topic names so
+    // patterns match them as published, and without any the mock dashboard is shown
+    let network_table: Box<dyn Backend> = if connections.is_empty() {
+        Box::new(mock::stressing_example(80))
+        //Box::new(mock::example_dashboard())
+    } else if let [connection] = connections.as_slice() {
+        Box::new(Nt::with_address(connection.address))
+    } else {
+        Box::new(
+            connections
+                .into_iter()
+                .fold(Multi::default(), |multi, connection| {
+                    multi.with(connection.root, Nt::with_address(connection.address))
+                }),
+        )
+    };
 
     // application state
     let mut app = App::new(
@@ -72,7 +92,8 @@ fn run() -> Result<()> {
             height: 10,
         },
         network_table,
-    );
+        config,
+    )?;
     t.draw(|f| app.render(f))?;
     let mut total_time = Duration::ZERO;
     let mut last;
@@ -83,7 +104,7 @@ fn run() -> Result<()> {
             None
         };
         last = Instant::now();
-        match app.update(&event) {
+        match app.update(event.as_ref()) {
             Ok(result) => {
                 if result {
                     break;
@@ -108,10 +129,22 @@ fn run() -> Result<()> {
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("bench") {
+        return bench::run();
+    }
+
+    // load the config and connections before taking over the terminal so errors are readable
+    let config = Config::load("smorgasbord.conf")?;
+    let connections = args
+        .iter()
+        .map(|arg| arg.parse())
+        .collect::<Result<_, _>>()?;
+
     // setup terminal
     startup()?;
 
-    let result = run();
+    let result = run(&config, connections);
 
     // teardown terminal before unwrapping Result of app run
     shutdown()?;
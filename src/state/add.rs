@@ -0,0 +1,233 @@
+use std::{fmt, str::FromStr};
+
+use network_tables::Value;
+use thiserror::Error;
+use tui_input::Input;
+
+use crate::backend::{Backend, Entry, Path, PathError};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EntryType {
+    Boolean,
+    Double,
+    Float,
+    Int,
+    String,
+    BooleanArray,
+    DoubleArray,
+    FloatArray,
+    IntArray,
+    StringArray,
+}
+
+const ENTRY_TYPES: [EntryType; 10] = [
+    EntryType::Boolean,
+    EntryType::Double,
+    EntryType::Float,
+    EntryType::Int,
+    EntryType::String,
+    EntryType::BooleanArray,
+    EntryType::DoubleArray,
+    EntryType::FloatArray,
+    EntryType::IntArray,
+    EntryType::StringArray,
+];
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{0}")]
+    Path(PathError),
+    #[error("{0}")]
+    Unwritable(anyhow::Error),
+    #[error("unknown type {0}")]
+    UnknownType(String),
+    #[error("{0:?} isn't a boolean")]
+    NotBoolean(String),
+    #[error("{0:?} isn't a double")]
+    NotDouble(String),
+    #[error("{0:?} isn't a float")]
+    NotFloat(String),
+    #[error("{0:?} isn't an int")]
+    NotInt(String),
+}
+
+impl fmt::Display for EntryType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Boolean => "boolean",
+            Self::Double => "double",
+            Self::Float => "float",
+            Self::Int => "int",
+            Self::String => "string",
+            Self::BooleanArray => "boolean[]",
+            Self::DoubleArray => "double[]",
+            Self::FloatArray => "float[]",
+            Self::IntArray => "int[]",
+            Self::StringArray => "string[]",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for EntryType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        ENTRY_TYPES
+            .into_iter()
+            .find(|r#type| r#type.to_string() == s)
+            .ok_or_else(|| Error::UnknownType(s.to_string()))
+    }
+}
+
+impl EntryType {
+    /// The type of an existing value, if it's one that can be entered.
+    pub fn of(value: &Value) -> Option<Self> {
+        let r#type = match value {
+            Value::Boolean(_) => Self::Boolean,
+            Value::F32(_) => Self::Float,
+            Value::F64(_) => Self::Double,
+            Value::Integer(_) => Self::Int,
+            Value::String(_) => Self::String,
+            Value::Array(elements) => match Self::of(elements.first()?)? {
+                Self::Boolean => Self::BooleanArray,
+                Self::Double => Self::DoubleArray,
+                Self::Float => Self::FloatArray,
+                Self::Int => Self::IntArray,
+                Self::String => Self::StringArray,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(r#type)
+    }
+
+    fn parse_scalar(self, text: &str) -> Result<Value, Error> {
+        let text = text.trim();
+        match self {
+            Self::Boolean | Self::BooleanArray => text
+                .parse::<bool>()
+                .map(Value::from)
+                .map_err(|_| Error::NotBoolean(text.to_string())),
+            Self::Double | Self::DoubleArray => text
+                .parse::<f64>()
+                .map(Value::from)
+                .map_err(|_| Error::NotDouble(text.to_string())),
+            Self::Float | Self::FloatArray => text
+                .parse::<f32>()
+                .map(Value::from)
+                .map_err(|_| Error::NotFloat(text.to_string())),
+            Self::Int | Self::IntArray => text
+                .parse::<i64>()
+                .map(Value::from)
+                .map_err(|_| Error::NotInt(text.to_string())),
+            Self::String | Self::StringArray => Ok(Value::from(text)),
+        }
+    }
+
+    const fn is_array(self) -> bool {
+        matches!(
+            self,
+            Self::BooleanArray
+                | Self::DoubleArray
+                | Self::FloatArray
+                | Self::IntArray
+                | Self::StringArray
+        )
+    }
+
+    /// Parse the text of the value prompt. Arrays are written as comma separated elements.
+    pub fn parse(self, text: &str) -> Result<Value, Error> {
+        if !self.is_array() {
+            if self == Self::String {
+                return Ok(Value::from(text));
+            }
+            return self.parse_scalar(text);
+        }
+        if text.trim().is_empty() {
+            return Ok(Value::Array(Vec::new()));
+        }
+        let elements: Result<Vec<_>, _> = text
+            .split(',')
+            .map(|element| self.parse_scalar(element))
+            .collect();
+        elements.map(Value::Array)
+    }
+}
+
+pub enum Step {
+    Path,
+    Type(Path),
+    Value(Path, EntryType),
+}
+
+pub struct Add {
+    pub step: Step,
+    pub text_field: Input,
+    pub error: Option<Error>,
+}
+
+impl Default for Add {
+    fn default() -> Self {
+        Self {
+            step: Step::Path,
+            text_field: Input::new(String::new()),
+            error: None,
+        }
+    }
+}
+
+impl Add {
+    pub fn prompt(&self) -> String {
+        match &self.step {
+            Step::Path => String::from("Enter the path of the new entry"),
+            Step::Type(path) => {
+                let types: Vec<_> = ENTRY_TYPES.iter().map(ToString::to_string).collect();
+                format!("Enter the type of {path} ({})", types.join(", "))
+            }
+            Step::Value(path, EntryType::String) => format!("Enter the initial value of {path}"),
+            Step::Value(path, r#type) if r#type.is_array() => {
+                format!("Enter the initial {type} elements of {path}, separated by commas")
+            }
+            Step::Value(path, r#type) => format!("Enter the initial {type} value of {path}"),
+        }
+    }
+
+    /// Consume the text field and advance to the next step, returning the entry once the value has been entered.
+    /// The path is checked against the backend, so that it's never shown without being published.
+    pub fn submit(&mut self, backend: &impl Backend) -> Option<Entry> {
+        let text = self.text_field.value().to_string();
+        let result = match &self.step {
+            Step::Path => text
+                .parse()
+                .map_err(Error::Path)
+                .and_then(|path| {
+                    backend
+                        .check_write(&path)
+                        .map_err(Error::Unwritable)
+                        .map(|()| path)
+                })
+                .map(Step::Type),
+            Step::Type(path) => text.parse().map(|r#type| Step::Value(path.clone(), r#type)),
+            Step::Value(path, r#type) => match r#type.parse(&text) {
+                Ok(value) => {
+                    return Some(Entry {
+                        path: path.clone(),
+                        value,
+                    })
+                }
+                Err(error) => Err(error),
+            },
+        };
+        match result {
+            Ok(step) => {
+                self.step = step;
+                self.text_field.reset();
+                self.error = None;
+            }
+            Err(error) => self.error = Some(error),
+        }
+        None
+    }
+}
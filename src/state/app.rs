@@ -1,17 +1,22 @@
 use anyhow::Result;
 use crossterm::event::{self, KeyCode::Char};
 use crossterm::event::{Event, KeyCode};
-use std::time::Instant;
+use std::{collections::BTreeMap, time::Instant};
 
-use crate::backend::{Backend, Update};
+use crate::backend::{Backend, Entry, Key, Path, Update, Write};
+use crate::config::Config;
 use crate::state::packing::GridPosition;
-use crate::widget_tree::Tree;
-use crate::widgets::tabs::{self, Filter};
-use crate::widgets::{self, sendable_chooser, simple, Size};
+use crate::widget_tree::{Change, Tree};
+use crate::widgets::tabs::Filter;
+use crate::widgets::{self, Size};
 
 use thiserror::Error;
+use tracing::Level;
 use tui_input::{Input, InputRequest};
 
+use super::add::Add;
+use super::browser::Browser;
+use super::fms::{self, FmsInfo};
 use super::packing::Packing;
 
 pub struct App<B> {
@@ -22,11 +27,22 @@ pub struct App<B> {
     pub state: State,
     pub start_time: Instant,
     pub filter: Filter,
+    pub show_diagnostics: bool,
+    pub browser: Browser,
+    // entries to write once their time comes, from `Write::then_after`
+    pub scheduled: Vec<(Instant, Entry)>,
+    // shown in the title bar rather than as widgets, once the driver station publishes it, keyed
+    // by the root of the connection it's from when there are several
+    pub fms: BTreeMap<Option<Key>, FmsInfo>,
+    // the roots that `Backend::connections` namespaces each connection under
+    connection_roots: Vec<Key>,
 }
 
 pub enum State {
     View,
     Edit(Edit),
+    Add(Add),
+    Browse,
 }
 
 pub struct Edit {
@@ -44,104 +60,292 @@ pub enum Error {
 }
 
 impl<B: Backend> App<B> {
-    pub fn update(&mut self, event: &Option<Event>) -> Result<bool> {
+    pub fn update(&mut self, event: Option<&Event>) -> Result<bool> {
         self.check_health()?;
 
         if let Some(Event::Key(key)) = event {
-            if key.kind == event::KeyEventKind::Press {
-                match &mut self.state {
-                    State::View => match key.code {
-                        Char('q') => return Ok(true),
-                        KeyCode::Left => {
-                            self.cursor.x = self.cursor.x.saturating_sub(1);
-                        }
-                        KeyCode::Right => {
-                            self.cursor.x = (self.cursor.x + 1).min(self.packing.get_width() - 1);
-                        }
-                        KeyCode::Up => {
-                            self.cursor.y = self.cursor.y.saturating_sub(1);
-                        }
-                        KeyCode::Down => {
-                            self.cursor.y = (self.cursor.y + 1).min(self.packing.get_height() - 1);
-                        }
-                        KeyCode::Enter => {
-                            self.try_edit();
-                        }
-                        _ => {}
-                    },
-                    State::Edit(edit) => {
-                        let Some(widget) = self
-                            .packing
-                            .get_mut_widget(edit.editting, &mut self.widget_tree)
-                        else {
-                            return Err(Error::RunawayWidget {
-                                position: edit.editting,
-                            }
-                            .into());
-                        };
-                        match key.code {
-                            KeyCode::Enter => {
-                                let write = widget.update(edit.text_field.value());
-                                self.network_table.write(write.entries().cloned().collect());
-
-                                if let Some(filter) = write.try_filter() {
-                                    self.packing.clear();
-                                    self.filter = filter;
-                                }
-
-                                edit.text_field.reset();
-                            }
-                            KeyCode::Left => {
-                                edit.text_field.handle(InputRequest::GoToPrevChar);
-                            }
-                            KeyCode::Right => {
-                                edit.text_field.handle(InputRequest::GoToNextChar);
-                            }
-                            KeyCode::Backspace => {
-                                edit.text_field.handle(InputRequest::DeletePrevChar);
-                            }
-                            Char(c) => {
-                                edit.text_field.handle(InputRequest::InsertChar(c));
-                            }
-                            KeyCode::Esc => {
-                                self.state = State::View;
-                                return Ok(false);
-                            }
-                            _ => {}
-                        };
-                        if widget.is_finished() {
-                            self.state = State::View;
-                        }
+            if key.kind == event::KeyEventKind::Press && self.handle_key(key.code)? {
+                return Ok(true);
+            }
+        }
+
+        self.write_scheduled();
+
+        self.update_tree()?;
+
+        Ok(false)
+    }
+
+    // returns whether the app should quit
+    fn handle_key(&mut self, code: KeyCode) -> Result<bool> {
+        match &mut self.state {
+            State::View => match code {
+                Char('q') => return Ok(true),
+                KeyCode::Left => {
+                    self.cursor.x = self.cursor.x.saturating_sub(1);
+                }
+                KeyCode::Right => {
+                    self.cursor.x = (self.cursor.x + 1).min(self.packing.get_width() - 1);
+                }
+                KeyCode::Up => {
+                    self.cursor.y = self.cursor.y.saturating_sub(1);
+                }
+                KeyCode::Down => {
+                    self.cursor.y = (self.cursor.y + 1).min(self.packing.get_height() - 1);
+                }
+                KeyCode::Enter => {
+                    if !self.widget_key(code) {
+                        self.try_edit();
+                    }
+                }
+                Char('a') => {
+                    self.state = State::Add(Add::default());
+                }
+                Char('v') => {
+                    if let Some(path) = self.packing.get_path(self.cursor).cloned() {
+                        self.widget_tree.cycle(&path);
+                    }
+                }
+                Char('V') => {
+                    if let Some(path) = self.packing.get_path(self.cursor).cloned() {
+                        self.widget_tree.collapse(&path);
+                    }
+                }
+                Char('d') => {
+                    self.show_diagnostics = !self.show_diagnostics;
+                }
+                Char('t') => {
+                    self.state = State::Browse;
+                }
+                code => {
+                    self.widget_key(code);
+                }
+            },
+            State::Browse => self.handle_browse_key(code),
+            State::Add(add) => match code {
+                KeyCode::Enter => {
+                    if let Some(entry) = add.submit(&self.network_table) {
+                        self.network_table.write(vec![entry.clone()]);
+                        self.widget_tree.create_entry(entry)?;
+                        self.state = State::View;
                     }
                 }
+                KeyCode::Esc => {
+                    self.state = State::View;
+                }
+                code => handle_input(&mut add.text_field, code),
+            },
+            State::Edit(_) => self.handle_edit_key(code)?,
+        }
+
+        Ok(false)
+    }
+
+    fn handle_edit_key(&mut self, code: KeyCode) -> Result<()> {
+        let State::Edit(edit) = &mut self.state else {
+            return Ok(());
+        };
+        let Some(widget) = self
+            .packing
+            .get_mut_widget(edit.editting, &mut self.widget_tree)
+        else {
+            return Err(Error::RunawayWidget {
+                position: edit.editting,
+            }
+            .into());
+        };
+        let write = match code {
+            KeyCode::Enter => {
+                let write = widget.update(edit.text_field.value());
+                edit.text_field.reset();
+                Some(write)
+            }
+            KeyCode::Up | KeyCode::Down => widget.handle_key(code),
+            KeyCode::Esc => {
+                self.state = State::View;
+                return Ok(());
+            }
+            code => {
+                handle_input(&mut edit.text_field, code);
+                None
+            }
+        };
+        // the prompt may now explain why the input was rejected
+        edit.prompt = widget.prompt();
+        if widget.is_finished() {
+            self.state = State::View;
+        }
+        if let Some(write) = write {
+            self.write(&write);
+        }
+        Ok(())
+    }
+
+    // offers the key to the widget under the cursor, returning whether it used it
+    fn widget_key(&mut self, code: KeyCode) -> bool {
+        let Some(write) = self
+            .packing
+            .get_mut_widget(self.cursor, &mut self.widget_tree)
+            .and_then(|widget| widget.handle_key(code))
+        else {
+            return false;
+        };
+        self.write(&write);
+        true
+    }
+
+    fn write_scheduled(&mut self) {
+        let now = Instant::now();
+        let (due, waiting) = std::mem::take(&mut self.scheduled)
+            .into_iter()
+            .partition(|(time, _)| *time <= now);
+        self.scheduled = waiting;
+        let due: Vec<_> = due.into_iter().map(|(_, entry)| entry).collect();
+        if due.is_empty() {
+            return;
+        }
+        // written first so that a momentary button is always released on the robot, even if its
+        // widget has since gone away
+        self.network_table.write(due.clone());
+        // the widget that scheduled the write has moved on since, so it learns about it like any other update
+        for entry in due {
+            let path = entry.path.clone();
+            if let Err(error) = self.widget_tree.update_entry(entry) {
+                tracing::event!(
+                    Level::WARN,
+                    "couldn't show the scheduled write to {path}: {error}"
+                );
+            }
+        }
+    }
+
+    fn write(&mut self, write: &Write) {
+        self.network_table.write(write.entries().cloned().collect());
+        let now = Instant::now();
+        self.scheduled.extend(
+            write
+                .delayed()
+                .map(|(delay, entry)| (now + *delay, entry.clone())),
+        );
+        if let Some(filter) = write.try_filter() {
+            self.filter = filter;
+            self.repack();
+        }
+    }
+
+    fn handle_browse_key(&mut self, code: KeyCode) {
+        let browser = &mut self.browser;
+        browser.message = None;
+        if browser.searching {
+            match code {
+                KeyCode::Enter => browser.searching = false,
+                KeyCode::Esc => {
+                    browser.searching = false;
+                    browser.search.reset();
+                }
+                code => handle_input(&mut browser.search, code),
             }
+            return;
         }
+        match code {
+            KeyCode::Up => browser.move_selection(&self.widget_tree, -1),
+            KeyCode::Down => browser.move_selection(&self.widget_tree, 1),
+            KeyCode::Right => browser.expand(),
+            KeyCode::Left => browser.collapse(),
+            Char('/') => browser.searching = true,
+            KeyCode::Enter => {
+                if let Some(path) = browser.selected.clone() {
+                    self.jump_to(&path);
+                }
+            }
+            KeyCode::Esc | Char('t') => self.state = State::View,
+            _ => {}
+        }
+    }
+
+    // moves the cursor to the widget showing the path, which may be on one of its ancestors
+    fn jump_to(&mut self, path: &Path) {
+        let mut shown = Some(path.clone());
+        while let Some(path) = shown {
+            if let Some((position, _)) = self.packing.positions.get(&path) {
+                self.cursor = *position;
+                self.state = State::View;
+                return;
+            }
+            shown = path.parent();
+        }
+        self.browser.message = Some(format!("No widget in the grid shows {path}"));
+    }
 
+    fn update_tree(&mut self) -> Result<()> {
         let Update {
             to_update,
             to_create,
         } = self.network_table.update();
 
         for entry in to_update {
+            self.update_fms(&entry);
             self.widget_tree.update_entry(entry)?;
         }
 
         for entry in to_create {
+            self.update_fms(&entry);
             self.widget_tree.create_entry(entry)?;
         }
 
-        // TODO: implement a better filtering method
+        let mut added = Vec::new();
+        let mut has_removed = false;
+        for change in self.widget_tree.drain_changes() {
+            match change {
+                Change::Added(path) => added.push(path),
+                Change::Removed(path) => {
+                    self.packing.remove(&path);
+                    has_removed = true;
+                }
+            }
+        }
+        // widgets that didn't fit before may fit in the space left by the removed ones
+        if has_removed {
+            added.extend(self.packing.unplaced.iter().cloned());
+        }
+
+        // widgets that were removed again after being added are no longer in the tree
+        let new_widgets = added
+            .iter()
+            .filter(|path| self.is_in_grid(path))
+            .filter_map(|path| Some((path, self.widget_tree.get(path)?.size())))
+            .collect();
+
+        self.packing.add_all(new_widgets);
+
+        Ok(())
+    }
+
+    fn update_fms(&mut self, entry: &Entry) {
+        let Some((root, key)) = fms::key(&entry.path, &self.connection_roots) else {
+            return;
+        };
+        self.fms
+            .entry(root.cloned())
+            .or_default()
+            .update(key, &entry.value);
+    }
+
+    // FMSInfo is shown in the title bar rather than the grid, but can still be browsed
+    fn is_in_grid(&self, path: &Path) -> bool {
+        self.filter.contains(path) && !fms::is_fms(path, &self.connection_roots)
+    }
+
+    fn repack(&mut self) {
+        self.packing.clear();
         let all_widgets = self
             .widget_tree
             .widgets()
             .into_iter()
-            .filter(|widget| self.filter.contains(widget))
+            .filter(|widget| self.is_in_grid(&widget.title))
             .map(|widget| (&widget.title, widget.size()))
             .collect();
-
         self.packing.add_all(all_widgets);
-
-        Ok(false)
     }
 
     fn try_edit(&mut self) {
@@ -176,14 +380,16 @@ impl<B: Backend> App<B> {
         Ok(())
     }
 
-    pub fn new(size: Size, network_table: B) -> Self {
-        let builders: Vec<Box<dyn widgets::Builder>> = vec![
-            Box::new(simple::Builder),
-            Box::new(sendable_chooser::Builder),
-            Box::new(tabs::Builder),
-        ];
+    pub fn new(size: Size, network_table: B, config: &Config) -> Result<Self> {
+        let mut builders = config.builders()?;
+        builders.extend(widgets::builders());
         let widget_tree = Tree::new(builders);
-        Self {
+        let connection_roots = network_table
+            .connections()
+            .into_iter()
+            .map(|(root, _)| root)
+            .collect();
+        Ok(Self {
             packing: Packing::new(size),
             network_table,
             cursor: GridPosition::default(),
@@ -191,6 +397,22 @@ impl<B: Backend> App<B> {
             widget_tree,
             start_time: Instant::now(),
             filter: Filter::default(),
-        }
+            show_diagnostics: false,
+            browser: Browser::default(),
+            scheduled: Vec::new(),
+            fms: BTreeMap::new(),
+            connection_roots,
+        })
     }
 }
+
+fn handle_input(text_field: &mut Input, code: KeyCode) {
+    let request = match code {
+        KeyCode::Left => InputRequest::GoToPrevChar,
+        KeyCode::Right => InputRequest::GoToNextChar,
+        KeyCode::Backspace => InputRequest::DeletePrevChar,
+        Char(c) => InputRequest::InsertChar(c),
+        _ => return,
+    };
+    text_field.handle(request);
+}
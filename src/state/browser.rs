@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use tui_input::Input;
+
+use crate::{
+    backend::{Key, Path},
+    widget_tree::{Nodes, Tree, Value},
+};
+
+/// One line of the browser, a node of the tree at some depth.
+pub struct Row {
+    pub path: Path,
+    pub depth: usize,
+    /// `None` for values, otherwise whether the branch's children are shown
+    pub expanded: Option<bool>,
+    pub value: Option<network_tables::Value>,
+    pub has_widget: bool,
+}
+
+/// Browses every node of the tree, including the ones without widgets, like `OutlineViewer`.
+#[derive(Default)]
+pub struct Browser {
+    pub expanded: HashSet<Path>,
+    pub selected: Option<Path>,
+    pub search: Input,
+    pub searching: bool,
+    pub message: Option<String>,
+}
+
+impl Browser {
+    /// The lines to show, searching shows every match and its ancestors expanded.
+    pub fn rows(&self, tree: &Tree) -> Vec<Row> {
+        let mut rows = Vec::new();
+        let query = self.search.value().to_lowercase();
+        for (leading_slash, nodes) in tree.roots() {
+            self.add_rows(nodes, leading_slash, &mut Vec::new(), &query, &mut rows);
+        }
+        rows
+    }
+
+    // returns whether any of the nodes matched the query
+    fn add_rows(
+        &self,
+        nodes: &Nodes,
+        leading_slash: bool,
+        keys: &mut Vec<Key>,
+        query: &str,
+        rows: &mut Vec<Row>,
+    ) -> bool {
+        let mut any_matched = false;
+        let mut sorted: Vec<_> = nodes.iter().collect();
+        sorted.sort_by(|a, b| a.key.as_str().cmp(b.key.as_str()));
+        for node in sorted {
+            keys.push(node.key.clone());
+            let mut path = Path::try_from(keys.clone()).unwrap();
+            path.leading_slash = leading_slash;
+            let matched = query.is_empty() || path.to_string().to_lowercase().contains(query);
+            let index = rows.len();
+            rows.push(Row {
+                path,
+                depth: keys.len() - 1,
+                expanded: None,
+                value: node
+                    .value
+                    .try_get_value()
+                    .or_else(|| node.own_value.clone()),
+                has_widget: !node.widgets.is_empty(),
+            });
+
+            let mut shown = matched;
+            if let Value::Branch(children) = &node.value {
+                let expanded = !query.is_empty() || self.expanded.contains(&rows[index].path);
+                rows[index].expanded = Some(expanded);
+                if expanded && self.add_rows(children, leading_slash, keys, query, rows) {
+                    shown = true;
+                }
+            }
+            if !shown {
+                rows.truncate(index);
+            }
+            any_matched |= shown;
+            keys.pop();
+        }
+        any_matched
+    }
+
+    fn selected_index(&self, rows: &[Row]) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        rows.iter().position(|row| &row.path == selected)
+    }
+
+    /// Move the selection by the offset, staying within the rows.
+    pub fn move_selection(&mut self, tree: &Tree, offset: isize) {
+        let rows = self.rows(tree);
+        if rows.is_empty() {
+            return;
+        }
+        let index = self
+            .selected_index(&rows)
+            .map_or(0, |index| index.saturating_add_signed(offset))
+            .min(rows.len() - 1);
+        self.selected = Some(rows[index].path.clone());
+    }
+
+    pub fn expand(&mut self) {
+        if let Some(path) = &self.selected {
+            self.expanded.insert(path.clone());
+        }
+    }
+
+    /// Collapse the selected branch, or go to the parent if it's already collapsed.
+    pub fn collapse(&mut self) {
+        let Some(path) = self.selected.clone() else {
+            return;
+        };
+        if self.expanded.remove(&path) {
+            return;
+        }
+        if let Some(parent) = path.parent() {
+            self.expanded.remove(&parent);
+            self.selected = Some(parent);
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use std::time::{Duration, Instant};
+
+use network_tables::Value;
+use ratatui::style::Color;
+
+use crate::{
+    backend::{Key, Path},
+    widgets::util::NTValue,
+};
+
+// the table the driver station publishes the match and the robot's state to
+const TABLE: &str = "FMSInfo";
+
+// how long the enabled periods of a match last
+const AUTONOMOUS_PERIOD: Duration = Duration::from_secs(15);
+const TELEOP_PERIOD: Duration = Duration::from_secs(135);
+
+// the bits of FMSControlData
+const ENABLED: i64 = 0x01;
+const AUTONOMOUS: i64 = 0x02;
+const TEST: i64 = 0x04;
+const EMERGENCY_STOP: i64 = 0x08;
+const FMS_ATTACHED: i64 = 0x10;
+const DS_ATTACHED: i64 = 0x20;
+
+/// What the robot is doing, decoded from the control word.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Disabled,
+    Autonomous,
+    Teleop,
+    Test,
+    EmergencyStopped,
+}
+
+impl Mode {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Disabled => "disabled",
+            Self::Autonomous => "auto",
+            Self::Teleop => "teleop",
+            Self::Test => "test",
+            Self::EmergencyStopped => "e-stopped",
+        }
+    }
+
+    const fn period(self) -> Option<Duration> {
+        match self {
+            Self::Autonomous => Some(AUTONOMOUS_PERIOD),
+            Self::Teleop => Some(TELEOP_PERIOD),
+            Self::Disabled | Self::Test | Self::EmergencyStopped => None,
+        }
+    }
+
+    const fn is_enabled(self) -> bool {
+        matches!(self, Self::Autonomous | Self::Teleop | Self::Test)
+    }
+}
+
+// the root of the connection the table is from, and the keys after it. The table is either at
+// `/FMSInfo` or, with several connections, at `/<root>/FMSInfo`
+fn table_keys<'a>(path: &'a Path, roots: &[Key]) -> Option<(Option<&'a Key>, &'a [Key])> {
+    if !path.leading_slash {
+        return None;
+    }
+    if path.first.as_str() == TABLE {
+        return Some((None, path.rest.as_slice()));
+    }
+    let (table, keys) = path.rest.split_first()?;
+    (table.as_str() == TABLE && roots.contains(&path.first)).then_some((Some(&path.first), keys))
+}
+
+/// Whether the path is the `FMSInfo` table of a connection or in it.
+pub fn is_fms(path: &Path, roots: &[Key]) -> bool {
+    table_keys(path, roots).is_some()
+}
+
+/// The root of the connection and the key of an entry in its `FMSInfo` table.
+pub fn key<'a>(path: &'a Path, roots: &[Key]) -> Option<(Option<&'a Key>, &'a Key)> {
+    match table_keys(path, roots)? {
+        (root, [key]) => Some((root, key)),
+        _ => None,
+    }
+}
+
+/// The match and robot state from the `FMSInfo` table.
+#[derive(Clone, Debug, Default)]
+pub struct FmsInfo {
+    event_name: String,
+    match_type: i64,
+    match_number: i64,
+    station: i64,
+    is_red_alliance: Option<bool>,
+    control_data: i64,
+    // the last mode the robot was enabled in and since when, so a brief disable doesn't restart it
+    enabled_mode: Option<(Mode, Instant)>,
+}
+
+impl FmsInfo {
+    pub fn update(&mut self, key: &Key, value: &Value) {
+        let integer = || value.as_i64().unwrap_or_default();
+        match key.as_str() {
+            "EventName" => self.event_name = value.try_to_string().unwrap_or_default(),
+            "MatchType" => self.match_type = integer(),
+            "MatchNumber" => self.match_number = integer(),
+            "StationNumber" => self.station = integer(),
+            "IsRedAlliance" => self.is_red_alliance = value.as_bool(),
+            "FMSControlData" => {
+                self.control_data = integer();
+                let mode = self.mode();
+                let is_new_mode = self
+                    .enabled_mode
+                    .is_none_or(|(enabled_mode, _)| enabled_mode != mode);
+                if mode.is_enabled() && is_new_mode {
+                    self.enabled_mode = Some((mode, Instant::now()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    const fn has(&self, bit: i64) -> bool {
+        self.control_data & bit != 0
+    }
+
+    pub const fn mode(&self) -> Mode {
+        if self.has(EMERGENCY_STOP) {
+            Mode::EmergencyStopped
+        } else if !self.has(ENABLED) {
+            Mode::Disabled
+        } else if self.has(TEST) {
+            Mode::Test
+        } else if self.has(AUTONOMOUS) {
+            Mode::Autonomous
+        } else {
+            Mode::Teleop
+        }
+    }
+
+    pub const fn alliance_color(&self) -> Option<Color> {
+        match self.is_red_alliance {
+            Some(true) => Some(Color::Red),
+            Some(false) => Some(Color::Blue),
+            None => None,
+        }
+    }
+
+    /// Like `Qualification 12 at Event, Red 2, FMS`.
+    pub fn summary(&self) -> String {
+        let match_type = match self.match_type {
+            1 => "Practice",
+            2 => "Qualification",
+            3 => "Elimination",
+            _ => "Match",
+        };
+        let mut parts = vec![if self.event_name.is_empty() {
+            format!("{match_type} {}", self.match_number)
+        } else {
+            format!("{match_type} {} at {}", self.match_number, self.event_name)
+        }];
+        if let Some(is_red) = self.is_red_alliance {
+            let alliance = if is_red { "Red" } else { "Blue" };
+            parts.push(format!("{alliance} {}", self.station));
+        }
+        if self.has(FMS_ATTACHED) {
+            parts.push(String::from("FMS"));
+        } else if self.has(DS_ATTACHED) {
+            parts.push(String::from("DS"));
+        }
+        parts.join(", ")
+    }
+
+    /// The time in the current mode, counted down from the length of its period in a match, like
+    /// `auto 0:12 left`. `FMSInfo` doesn't include the real match time, so this is only an
+    /// estimate from when the robot was enabled in the mode.
+    pub fn time_in_mode(&self) -> String {
+        let mode = self.mode();
+        let since = match self.enabled_mode {
+            Some((enabled_mode, since)) if mode.is_enabled() && enabled_mode == mode => since,
+            _ => return mode.name().to_string(),
+        };
+        let elapsed = since.elapsed();
+        let Some(period) = mode.period() else {
+            return format!("{} {}", mode.name(), format_time(elapsed.as_secs()));
+        };
+        // rounded up like a match clock, which only shows 0:00 once the period is over
+        let left = period.saturating_sub(elapsed);
+        let seconds = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+        format!("{} {} left", mode.name(), format_time(seconds))
+    }
+}
+
+fn format_time(seconds: u64) -> String {
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use network_tables::Value;
+    use ratatui::style::Color;
+
+    use super::{
+        is_fms, key, FmsInfo, Mode, AUTONOMOUS, DS_ATTACHED, EMERGENCY_STOP, ENABLED, FMS_ATTACHED,
+        TEST,
+    };
+    use crate::backend::{Key, Path};
+
+    fn info(entries: &[(&str, Value)]) -> FmsInfo {
+        let mut info = FmsInfo::default();
+        for (key, value) in entries {
+            info.update(&Key::from(*key), value);
+        }
+        info
+    }
+
+    fn mode(control_data: i64) -> Mode {
+        info(&[("FMSControlData", Value::from(control_data))]).mode()
+    }
+
+    #[test]
+    fn decodes_the_mode() {
+        assert_eq!(mode(0), Mode::Disabled);
+        assert_eq!(mode(AUTONOMOUS), Mode::Disabled);
+        assert_eq!(mode(ENABLED), Mode::Teleop);
+        assert_eq!(mode(ENABLED | AUTONOMOUS), Mode::Autonomous);
+        assert_eq!(mode(ENABLED | TEST), Mode::Test);
+        assert_eq!(mode(ENABLED | TEST | AUTONOMOUS), Mode::Test);
+        assert_eq!(
+            mode(ENABLED | AUTONOMOUS | EMERGENCY_STOP),
+            Mode::EmergencyStopped
+        );
+        assert_eq!(mode(EMERGENCY_STOP), Mode::EmergencyStopped);
+        assert_eq!(
+            mode(ENABLED | AUTONOMOUS | FMS_ATTACHED | DS_ATTACHED),
+            Mode::Autonomous
+        );
+    }
+
+    #[test]
+    fn summarizes_the_match_and_station() {
+        let red = info(&[
+            ("EventName", Value::from("Event")),
+            ("MatchType", Value::from(2)),
+            ("MatchNumber", Value::from(12)),
+            ("StationNumber", Value::from(2)),
+            ("IsRedAlliance", Value::from(true)),
+            ("FMSControlData", Value::from(FMS_ATTACHED | DS_ATTACHED)),
+        ]);
+        assert_eq!(red.summary(), "Qualification 12 at Event, Red 2, FMS");
+        assert_eq!(red.alliance_color(), Some(Color::Red));
+
+        let blue = info(&[
+            ("MatchNumber", Value::from(3)),
+            ("StationNumber", Value::from(1)),
+            ("IsRedAlliance", Value::from(false)),
+            ("FMSControlData", Value::from(DS_ATTACHED)),
+        ]);
+        assert_eq!(blue.summary(), "Match 3, Blue 1, DS");
+        assert_eq!(blue.alliance_color(), Some(Color::Blue));
+
+        let unknown = info(&[("MatchType", Value::from(1))]);
+        assert_eq!(unknown.summary(), "Practice 0");
+        assert_eq!(unknown.alliance_color(), None);
+    }
+
+    #[test]
+    fn counts_down_the_enabled_period() {
+        let auto = info(&[("FMSControlData", Value::from(ENABLED | AUTONOMOUS))]);
+        assert_eq!(auto.time_in_mode(), "auto 0:15 left");
+        let disabled = info(&[("FMSControlData", Value::from(0))]);
+        assert_eq!(disabled.time_in_mode(), "disabled");
+    }
+
+    #[test]
+    fn only_finds_the_table_at_the_top_of_a_connection() {
+        let roots = [Key::from("robot")];
+        let path = |topic: &str| topic.parse::<Path>().unwrap();
+
+        let top = path("/FMSInfo/IsRedAlliance");
+        let (root, entry) = key(&top, &roots).unwrap();
+        assert!(root.is_none());
+        assert_eq!(entry.as_str(), "IsRedAlliance");
+
+        let namespaced = path("/robot/FMSInfo/StationNumber");
+        let (root, entry) = key(&namespaced, &roots).unwrap();
+        assert_eq!(root.map(Key::as_str), Some("robot"));
+        assert_eq!(entry.as_str(), "StationNumber");
+
+        assert!(is_fms(&path("/robot/FMSInfo"), &roots));
+        assert!(!is_fms(
+            &path("/SmartDashboard/FMSInfo/StationNumber"),
+            &roots
+        ));
+        assert!(!is_fms(&path("FMSInfo/StationNumber"), &roots));
+        assert!(key(&path("/FMSInfo/a/b"), &roots).is_none());
+    }
+}
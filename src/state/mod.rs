@@ -1,4 +1,7 @@
+pub mod add;
 pub mod app;
+pub mod browser;
+pub mod fms;
 pub mod packing;
 
 pub use app::App;
@@ -15,18 +15,19 @@ pub struct GridPosition {
 
 pub struct Packing {
     pub size: Size,
-    pub widgets: HashMap<GridPosition, Path>,
-    pub titles: HashSet<Path>,
+    pub positions: HashMap<Path, (GridPosition, Size)>,
     pub occupied: Vec<bool>,
+    /// widgets that didn't fit when they were added, to retry once others are removed
+    pub unplaced: HashSet<Path>,
 }
 
 impl Packing {
     pub fn new(size: Size) -> Self {
         Self {
             size,
-            widgets: HashMap::default(),
-            titles: HashSet::default(),
+            positions: HashMap::default(),
             occupied: vec![false; size.width * size.height],
+            unplaced: HashSet::default(),
         }
     }
 
@@ -56,17 +57,28 @@ impl Packing {
         true
     }
 
-    fn insert(&mut self, position: GridPosition, size: Size, path: Path) {
+    fn set_area_occupied(&mut self, position: GridPosition, size: Size, is_occupied: bool) {
         for row in position.y..position.y + size.height {
             for col in position.x..position.x + size.width {
-                self.set_occupied(GridPosition { x: col, y: row }, true);
+                self.set_occupied(GridPosition { x: col, y: row }, is_occupied);
             }
         }
-        self.titles.insert(path.clone());
-        self.widgets.insert(position, path);
     }
 
-    fn add_unchecked(&mut self, path: Path, size: Size) {
+    fn insert(&mut self, position: GridPosition, size: Size, path: Path) {
+        self.set_area_occupied(position, size, true);
+        self.positions.insert(path, (position, size));
+    }
+
+    pub fn remove(&mut self, path: &Path) {
+        if let Some((position, size)) = self.positions.remove(path) {
+            self.set_area_occupied(position, size, false);
+        }
+        self.unplaced.remove(path);
+    }
+
+    // returns whether there was space for the widget
+    fn add_unchecked(&mut self, path: Path, size: Size) -> bool {
         for start_row in 0..self.size.height {
             for start_col in 0..self.size.width {
                 let position = GridPosition {
@@ -76,10 +88,11 @@ impl Packing {
 
                 if self.fits(position, size) {
                     self.insert(position, size, path);
-                    return;
+                    return true;
                 }
             }
         }
+        false
     }
 
     pub const fn get_width(&self) -> usize {
@@ -90,55 +103,55 @@ impl Packing {
         self.size.height
     }
 
+    pub fn get_path(&self, position: GridPosition) -> Option<&Path> {
+        self.positions.iter().find_map(|(path, (start, size))| {
+            let contains = start.x <= position.x
+                && position.x < start.x + size.width
+                && start.y <= position.y
+                && position.y < start.y + size.height;
+            if contains {
+                Some(path)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn get_mut_widget<'a>(
         &self,
         position: GridPosition,
         tree: &'a mut Tree,
     ) -> Option<&'a mut Widget> {
-        let mut pos = None;
-        'find: for row in (0..=position.y).rev() {
-            for col in (0..=position.x).rev() {
-                let current = GridPosition { x: col, y: row };
-                let path = self.widgets.get(&current);
-                let widget = path.map(|path| tree.get(path).unwrap());
-                if widget.is_some() {
-                    let size = widget.unwrap().size();
-                    if row + size.height > position.y && col + size.width > position.x {
-                        pos = Some(current);
-                        break 'find;
-                    }
-                }
-            }
-        }
-        let path = self.widgets.get(&pos?).unwrap();
-        tree.get_mut(path)
+        tree.get_mut(self.get_path(position)?)
     }
 
-    pub fn widget<'a>(&'a self, tree: &'a Tree) -> packing::View<'a> {
-        let mut widgets = HashMap::new();
-        for (position, path) in &self.widgets {
-            widgets.insert(*position, tree.get(path).unwrap());
-        }
+    pub const fn widget<'a>(&'a self, tree: &'a Tree) -> packing::View<'a> {
         packing::View {
             size: self.size,
-            widgets,
-            titles: &self.titles,
+            widgets: &self.positions,
+            tree,
         }
     }
 
     pub fn clear(&mut self) {
-        self.widgets.clear();
-        self.titles.clear();
+        self.positions.clear();
         self.occupied = vec![false; self.size.width * self.size.height];
+        self.unplaced.clear();
     }
 
     pub fn add_all(&mut self, mut all_widgets: Vec<(&Path, Size)>) {
-        all_widgets.retain(|(path, _)| !self.titles.contains(path));
         all_widgets.sort_by_key(|(_, size)| size.area());
         all_widgets.reverse();
 
         for (path, size) in all_widgets {
-            self.add_unchecked(path.clone(), size);
+            if self.positions.contains_key(path) {
+                continue;
+            }
+            if self.add_unchecked(path.clone(), size) {
+                self.unplaced.remove(path);
+            } else {
+                self.unplaced.insert(path.clone());
+            }
         }
     }
 }
@@ -1,14 +1,28 @@
 use ratatui::{
     prelude::{Alignment, Buffer, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
-    widgets::{Block, Borders, Paragraph, StatefulWidget, Widget as UIWidget},
+    text::{Line, Span},
+    widgets::{
+        block::{Position, Title},
+        Block, Borders, List, ListItem, Paragraph, StatefulWidget, Widget as UIWidget,
+    },
     Frame,
 };
+use std::time::Duration;
+use tui_input::Input;
 
 use crate::{backend::Backend, state::App};
-use crate::{backend::Status, state::app::State};
+use crate::{
+    backend::{Key, Status},
+    state::{app::State, fms::FmsInfo},
+};
+
+use super::{browser, packing};
 
-use super::packing;
+const DIAGNOSTICS_WIDTH: u16 = 50;
+const BROWSER_WIDTH: u16 = 60;
+// partial builds often finish once the rest of a table arrives, so only the old ones are worrying
+const STUCK_AFTER: Duration = Duration::from_secs(5);
 
 impl<B: Backend> App<B> {
     pub fn render(&self, f: &mut Frame<'_>) {
@@ -23,7 +37,30 @@ impl<B: Backend> App<B> {
 
         self.render_title(chunks[0], f.buffer_mut());
 
-        self.render_grid(chunks[1], f.buffer_mut());
+        let browsing = matches!(self.state, State::Browse);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(if browsing { BROWSER_WIDTH } else { 0 }),
+                Constraint::Length(if self.show_diagnostics {
+                    DIAGNOSTICS_WIDTH
+                } else {
+                    0
+                }),
+            ])
+            .split(chunks[1]);
+        self.render_grid(columns[0], f.buffer_mut());
+        if browsing {
+            browser::View {
+                browser: &self.browser,
+                tree: &self.widget_tree,
+            }
+            .render(columns[1], f.buffer_mut());
+        }
+        if self.show_diagnostics {
+            self.render_diagnostics(columns[2], f.buffer_mut());
+        }
 
         self.render_edit_window(chunks[2], f.buffer_mut());
     }
@@ -39,7 +76,7 @@ impl<B: Backend> App<B> {
             .split(area);
         let mut cursor_state = packing::State {
             selected: match self.state {
-                State::View => false,
+                State::View | State::Add(_) | State::Browse => false,
                 State::Edit(_) => true,
             },
             cursor: self.cursor,
@@ -53,7 +90,7 @@ impl<B: Backend> App<B> {
 
         let mut cursor_state = packing::State {
             selected: match self.state {
-                State::View => false,
+                State::View | State::Add(_) | State::Browse => false,
                 State::Edit(_) => true,
             },
             cursor: self.cursor,
@@ -64,29 +101,84 @@ impl<B: Backend> App<B> {
         packing_view.render(area, buf, &mut cursor_state);
     }
 
+    fn render_diagnostics(&self, area: Rect, buf: &mut Buffer) {
+        let items: Vec<_> = self
+            .widget_tree
+            .diagnostics()
+            .into_iter()
+            .map(|diagnostic| {
+                let color = if diagnostic.since.elapsed() > STUCK_AFTER {
+                    Color::Red
+                } else {
+                    Color::Yellow
+                };
+                ListItem::new(format!("{}: {}", diagnostic.path, diagnostic.error))
+                    .style(Style::default().fg(color))
+            })
+            .collect();
+        let list = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Diagnostics (d to hide)"),
+        );
+        UIWidget::render(list, area, buf);
+    }
+
     fn render_edit_window(&self, area: Rect, buf: &mut Buffer) {
         match &self.state {
             State::View => Paragraph::new("Smorgasbord")
                 .block(Block::new().borders(Borders::ALL))
                 .render(area, buf),
             State::Edit(edit) => {
-                let width = area.width.max(3) - 3;
-                let scroll = edit.text_field.visual_scroll(width as usize);
-                let input = Paragraph::new(edit.text_field.value())
-                    .scroll((0, u16::try_from(scroll).unwrap()))
-                    .block(
-                        Block::default()
-                            .borders(Borders::ALL)
-                            .title(edit.prompt.clone()),
+                render_input(
+                    &edit.text_field,
+                    Block::default().title(edit.prompt.clone()),
+                    area,
+                    buf,
+                );
+            }
+            State::Add(add) => {
+                let mut block = Block::default().title(add.prompt());
+                if let Some(error) = &add.error {
+                    block = block.title(
+                        Title::from(Span::styled(
+                            error.to_string(),
+                            Style::default().fg(Color::Red),
+                        ))
+                        .position(Position::Bottom),
                     );
-                input.render(area, buf);
+                }
+                render_input(&add.text_field, block, area, buf);
+            }
+            State::Browse => {
+                let browser = &self.browser;
+                let mut block = Block::default().title(if browser.searching {
+                    "Search paths, Enter to keep the results, Esc to clear"
+                } else {
+                    "↑↓ move, → expand, ← collapse, / search, Enter jump to widget, Esc close"
+                });
+                if let Some(message) = &browser.message {
+                    block = block.title(
+                        Title::from(Span::styled(
+                            message.clone(),
+                            Style::default().fg(Color::Red),
+                        ))
+                        .position(Position::Bottom),
+                    );
+                }
+                render_input(&browser.search, block, area, buf);
             }
         }
     }
 
     fn render_title(&self, area: Rect, buf: &mut Buffer) {
+        // the title bar takes the color of the robot's alliance during a match
+        let alliance_color = self.fms.values().find_map(FmsInfo::alliance_color);
         let title_block = Block::default()
             .borders(Borders::ALL)
+            .border_style(
+                alliance_color.map_or_else(Style::default, |color| Style::default().fg(color)),
+            )
             .style(Style::default());
 
         let layout = Layout::default()
@@ -98,26 +190,89 @@ impl<B: Backend> App<B> {
             ])
             .split(title_block.inner(area));
 
-        let title = Paragraph::new("Smorgasbord");
+        let diagnostics = self.widget_tree.diagnostics().len();
+        let mut title = vec![Span::raw("Smorgasbord")];
+        for (root, fms) in &self.fms {
+            title.push(Span::styled(
+                format!("  {}", with_root(root.as_ref(), fms.summary())),
+                fms.alliance_color()
+                    .map_or_else(Style::default, |color| Style::default().fg(color)),
+            ));
+        }
+        if diagnostics > 0 && !self.show_diagnostics {
+            title.push(Span::styled(
+                format!(
+                    "  {diagnostics} diagnostic{} (d to show)",
+                    if diagnostics == 1 { "" } else { "s" }
+                ),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        let title = Paragraph::new(Line::from(title));
 
-        let elapsed = Paragraph::new(format!("{:?}", self.start_time.elapsed()));
+        let elapsed = Paragraph::new(if self.fms.is_empty() {
+            format!("{:?}", self.start_time.elapsed())
+        } else {
+            self.fms
+                .iter()
+                .map(|(root, fms)| with_root(root.as_ref(), fms.time_in_mode()))
+                .collect::<Vec<_>>()
+                .join("  ")
+        });
 
-        let status = self.network_table.status();
+        let connections = self.network_table.connections();
 
         title_block.render(area, buf);
         title.render(layout[0], buf);
         elapsed.render(layout[1], buf);
-        status.render(layout[2], buf);
+        if connections.is_empty() {
+            self.network_table.status().render(layout[2], buf);
+        } else {
+            let spans: Vec<_> = connections
+                .iter()
+                .flat_map(|(root, status)| {
+                    let (color, _) = status_style(*status);
+                    [
+                        Span::raw(" "),
+                        Span::styled(root.to_string(), Style::default().fg(color)),
+                    ]
+                })
+                .collect();
+            Paragraph::new(Line::from(spans))
+                .alignment(Alignment::Right)
+                .render(layout[2], buf);
+        }
+    }
+}
+
+// names which connection the text is about, when there are several
+fn with_root(root: Option<&Key>, text: String) -> String {
+    match root {
+        Some(root) => format!("{root}: {text}"),
+        None => text,
     }
 }
 
+const fn status_style(status: Status) -> (Color, &'static str) {
+    if status.is_connected {
+        (Color::Green, "Connected")
+    } else {
+        (Color::Red, "Disconnected")
+    }
+}
+
+fn render_input(text_field: &Input, block: Block<'_>, area: Rect, buf: &mut Buffer) {
+    let width = area.width.max(3) - 3;
+    let scroll = text_field.visual_scroll(width as usize);
+    let input = Paragraph::new(text_field.value())
+        .scroll((0, u16::try_from(scroll).unwrap()))
+        .block(block.borders(Borders::ALL));
+    input.render(area, buf);
+}
+
 impl UIWidget for Status {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let (color, text) = if self.is_connected {
-            (Color::Green, "Connected")
-        } else {
-            (Color::Red, "Disconnected")
-        };
+        let (color, text) = status_style(self);
         let widget = Paragraph::new(text)
             .style(Style::default().fg(color))
             .alignment(Alignment::Right);
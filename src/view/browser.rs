@@ -0,0 +1,69 @@
+use ratatui::{
+    prelude::{Buffer, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, StatefulWidget},
+};
+
+use crate::{
+    state::{add::EntryType, browser::Browser},
+    widget_tree::Tree,
+};
+
+pub struct View<'a> {
+    pub browser: &'a Browser,
+    pub tree: &'a Tree,
+}
+
+impl View<'_> {
+    pub fn render(self, area: Rect, buf: &mut Buffer) {
+        let rows = self.browser.rows(self.tree);
+        let selected = self
+            .browser
+            .selected
+            .as_ref()
+            .and_then(|selected| rows.iter().position(|row| &row.path == selected));
+
+        let items: Vec<_> = rows
+            .iter()
+            .map(|row| {
+                let indent = "  ".repeat(row.depth);
+                let marker = match row.expanded {
+                    Some(true) => "▾ ",
+                    Some(false) => "▸ ",
+                    None => "  ",
+                };
+                let key = row.path.rest.last().unwrap_or(&row.path.first).to_string();
+                let key_style = if row.has_widget {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default()
+                };
+                let mut spans = vec![
+                    Span::raw(format!("{indent}{marker}")),
+                    Span::styled(key, key_style),
+                ];
+                if let Some(value) = &row.value {
+                    let r#type =
+                        EntryType::of(value).map_or_else(String::new, |r#type| r#type.to_string());
+                    spans.push(Span::styled(
+                        format!(" {type}"),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                    spans.push(Span::raw(format!(" {value}")));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let mut state = ListState::default().with_selected(selected);
+        List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("NetworkTables"),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .render(area, buf, &mut state);
+    }
+}
@@ -1,7 +1,4 @@
-use std::{
-    collections::{HashMap, HashSet},
-    rc::Rc,
-};
+use std::{collections::HashMap, rc::Rc};
 
 use ratatui::{
     prelude::{Constraint, Direction, Layout, Rect},
@@ -11,13 +8,14 @@ use ratatui::{
 use crate::{
     backend::Path,
     state::packing::GridPosition,
-    widgets::{self, Size, Widget},
+    widget_tree::Tree,
+    widgets::{self, Size},
 };
 
 pub struct View<'a> {
     pub size: Size,
-    pub widgets: HashMap<GridPosition, &'a Widget>,
-    pub titles: &'a HashSet<Path>,
+    pub widgets: &'a HashMap<Path, (GridPosition, Size)>,
+    pub tree: &'a Tree,
 }
 
 pub struct State {
@@ -55,16 +53,18 @@ impl StatefulWidget for View<'_> {
             })
             .collect();
 
-        for (position, widget) in self.widgets {
+        for (path, (position, size)) in self.widgets {
+            let Some(widget) = self.tree.get(path) else {
+                continue;
+            };
             let start = row_layouts[position.y][position.x];
-            let end = row_layouts[position.y + widget.size().height - 1]
-                [position.x + widget.size().width - 1];
+            let end = row_layouts[position.y + size.height - 1][position.x + size.width - 1];
             let rect = start.union(end);
 
             let in_cursor = state.cursor.x >= position.x
-                && state.cursor.x < position.x + widget.size().width
+                && state.cursor.x < position.x + size.width
                 && state.cursor.y >= position.y
-                && state.cursor.y < position.y + widget.size().height;
+                && state.cursor.y < position.y + size.height;
 
             let mut state = if in_cursor {
                 if state.selected {
@@ -76,7 +76,7 @@ impl StatefulWidget for View<'_> {
                 widgets::State::Unhighlighted
             };
 
-            widget.clone().render(rect, buf, &mut state);
+            widget.render(rect, buf, &mut state);
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{cmp::Reverse, collections::HashMap, fmt, time::Instant};
 
 use crate::{
     backend::{Entry, Key, Path},
@@ -8,6 +8,7 @@ use crate::{
 
 use anyhow::Result;
 use thiserror::Error;
+use tracing::{event, Level};
 
 #[derive(Debug, Error)]
 pub enum Error {
@@ -19,7 +20,7 @@ pub enum Error {
     NoSuchEntry(Vec<Key>, String, Vec<Key>),
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub struct BuilderIndex {
     pub index: usize,
 }
@@ -30,19 +31,113 @@ impl fmt::Debug for BuilderIndex {
     }
 }
 
+/// A change to which paths have a widget, recorded so that the packing can be updated incrementally.
+#[derive(Clone, Debug)]
+pub enum Change {
+    Added(Path),
+    Removed(Path),
+}
+
+struct Context<'a> {
+    builders: &'a [Box<dyn Builder>],
+    changes: &'a mut Vec<Change>,
+    observers: &'a mut Vec<Path>,
+    // the nodes that gained observer widgets, which haven't seen the rest of the tree yet
+    new_observers: &'a mut Vec<Path>,
+    // whether a widget on one of the ancestors of the current node covers it
+    hidden: bool,
+    // whether the topics under the current root start with a `/`
+    leading_slash: bool,
+}
+
+impl Context<'_> {
+    fn all_builders(&self) -> Vec<BuilderIndex> {
+        (0..self.builders.len())
+            .map(|index| BuilderIndex { index })
+            .collect()
+    }
+}
+
+fn to_path(leading_slash: bool, path: &[Key]) -> Path {
+    let mut path = Path::try_from(path.to_owned()).unwrap();
+    path.leading_slash = leading_slash;
+    path
+}
+
+/// Which of a node's widgets is shown.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum Selection {
+    /// the widget from the highest priority builder
+    #[default]
+    Default,
+    Builder(BuilderIndex),
+    /// no widget at all, so that the node's children are shown instead
+    Raw,
+}
+
+/// A builder that recognized a node but couldn't finish a widget for it, so it's retried as the node changes.
+#[derive(Debug)]
+pub struct Partial {
+    pub builder: BuilderIndex,
+    pub error: anyhow::Error,
+    // when the builder first failed, kept while it keeps failing
+    pub since: Instant,
+}
+
+/// A partial build, for showing to the user.
+pub struct Diagnostic<'a> {
+    pub path: Path,
+    pub error: &'a anyhow::Error,
+    pub since: Instant,
+}
+
 pub struct Node {
     pub key: Key,
-    pub widgets: Vec<Widget>,
-    pub partial_widgets: Vec<BuilderIndex>,
+    // sorted from the highest to the lowest priority builder
+    pub widgets: Vec<(BuilderIndex, Widget)>,
+    pub partial_widgets: Vec<Partial>,
     pub value: Value,
+    /// The value of a topic at the same path as the children of a branch, which isn't built into widgets.
+    pub own_value: Option<network_tables::Value>,
+    selection: Selection,
 }
 
 impl Node {
+    fn active_index(&self) -> Option<usize> {
+        let first = if self.widgets.is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        match self.selection {
+            Selection::Default => first,
+            Selection::Builder(builder) => self
+                .widgets
+                .iter()
+                .position(|(built, _)| *built == builder)
+                .or(first),
+            Selection::Raw => None,
+        }
+    }
+
+    fn active_builder(&self) -> Option<BuilderIndex> {
+        self.active_index().map(|index| self.widgets[index].0)
+    }
+
+    fn active(&self) -> Option<&Widget> {
+        self.active_index().map(|index| &self.widgets[index].1)
+    }
+
+    fn active_mut(&mut self) -> Option<&mut Widget> {
+        self.active_index().map(|index| &mut self.widgets[index].1)
+    }
+
     fn widgets<'a>(&'a self, widgets: &mut Vec<&'a Widget>) {
-        for widget in &self.widgets {
+        if let Some(widget) = self.active() {
             widgets.push(widget);
+        } else {
+            self.value.widgets(widgets);
         }
-        self.value.widgets(widgets);
     }
 
     fn update_entry(
@@ -50,57 +145,80 @@ impl Node {
         path: &mut Vec<Key>,
         rest: &[Key],
         value: network_tables::Value,
-        builders: &[Box<dyn Builder>],
+        context: &mut Context<'_>,
     ) -> Result<()> {
+        if !self.value.fits(rest) {
+            return self.create_entry(path, rest, value, context);
+        }
+
         path.push(self.key.clone());
 
-        if let Err(error) = self.value.update_entry(path, rest, value, builders) {
+        let hidden = context.hidden;
+        context.hidden |= self.active_index().is_some();
+        let result = self.value.update_entry(path, rest, value, context);
+        context.hidden = hidden;
+        if let Err(error) = result {
             path.pop();
             return Err(error);
         }
 
-        for widget in &mut self.widgets {
-            widget.update_nt(&self.key, &self.value);
-        }
+        self.update_widgets();
 
+        let partial: Vec<_> = self
+            .partial_widgets
+            .iter()
+            .map(|partial| partial.builder)
+            .collect();
         let (widgets, partials) = Self::run_builders(
             &self.key,
             &self.value,
             path,
-            builders,
-            &self.partial_widgets,
+            context.leading_slash,
+            context.builders,
+            &partial,
         );
 
-        if !widgets.is_empty() {
-            self.value.strip_widgets();
-        }
-
-        self.widgets.extend(widgets);
-        self.partial_widgets = partials;
+        self.add_widgets(path, widgets, context);
+        self.set_partials(partials);
 
         path.pop();
 
         Ok(())
     }
 
+    fn update_widgets(&mut self) {
+        for (_, widget) in &mut self.widgets {
+            widget.update_nt(&self.key, &self.value);
+        }
+    }
+
     fn run_builders(
         key: &Key,
         value: &Value,
         path: &[Key],
+        leading_slash: bool,
         builders: &[Box<dyn Builder>],
         partial_widgets: &[BuilderIndex],
-    ) -> (Vec<Widget>, Vec<BuilderIndex>) {
+    ) -> (Vec<(BuilderIndex, Widget)>, Vec<Partial>) {
         let mut widgets = Vec::new();
         let mut partials = Vec::new();
         for index in partial_widgets {
             let builder = builders[index.index].as_ref();
+            if !builder.applies_to(path) {
+                continue;
+            }
 
             match builder.create_kind(key, value) {
                 BuildResult::Complete(kind) => {
-                    widgets.push(Widget::new(Path::try_from(path.to_owned()).unwrap(), kind));
+                    widgets.push((*index, Widget::new(to_path(leading_slash, path), kind)));
                 }
-                BuildResult::Partial(_) => {
-                    partials.push(*index);
+                BuildResult::Partial(error) => {
+                    event!(Level::DEBUG, "partial build at {path:?}: {error}");
+                    partials.push(Partial {
+                        builder: *index,
+                        error,
+                        since: Instant::now(),
+                    });
                 }
                 BuildResult::None => {}
             }
@@ -108,44 +226,58 @@ impl Node {
         (widgets, partials)
     }
 
+    fn set_partials(&mut self, mut partials: Vec<Partial>) {
+        for partial in &mut partials {
+            if let Some(previous) = self
+                .partial_widgets
+                .iter()
+                .find(|previous| previous.builder == partial.builder)
+            {
+                partial.since = previous.since;
+            }
+        }
+        self.partial_widgets = partials;
+    }
+
     fn from_entry(
         path: &mut Vec<Key>,
         first: &Key,
         rest: &[Key],
         value: network_tables::Value,
-        builders: &[Box<dyn Builder>],
+        context: &mut Context<'_>,
     ) -> Result<Self> {
         path.push(first.clone());
-        let mut node_value = if rest.is_empty() {
+        let node_value = if rest.is_empty() {
             Value::Leaf(value)
         } else {
             let mut branches = Nodes::default();
-            if let Err(error) = branches.create_entry(path, &rest[0], &rest[1..], value, builders) {
+            if let Err(error) = branches.create_entry(path, &rest[0], &rest[1..], value, context) {
                 path.pop();
                 return Err(error);
             }
             Value::Branch(branches)
         };
 
-        let (widgets, partial_widgets) = Self::run_builders(
+        let (widgets, partials) = Self::run_builders(
             first,
             &node_value,
             path,
-            builders,
-            &(0..builders.len())
-                .map(|index| BuilderIndex { index })
-                .collect::<Vec<_>>(),
+            context.leading_slash,
+            context.builders,
+            &context.all_builders(),
         );
-        if !widgets.is_empty() {
-            node_value.strip_widgets();
-        }
-        path.pop();
-        Ok(Self {
+        let mut node = Self {
             key: first.clone(),
-            widgets,
-            partial_widgets,
+            widgets: Vec::new(),
+            partial_widgets: Vec::new(),
             value: node_value,
-        })
+            own_value: None,
+            selection: Selection::Default,
+        };
+        node.set_partials(partials);
+        node.add_widgets(path, widgets, context);
+        path.pop();
+        Ok(node)
     }
 
     fn create_entry(
@@ -153,40 +285,174 @@ impl Node {
         path: &mut Vec<Key>,
         rest: &[Key],
         value: network_tables::Value,
-        builders: &[Box<dyn Builder>],
+        context: &mut Context<'_>,
     ) -> Result<()> {
         path.push(self.key.clone());
 
-        if let Err(error) = self.value.create_entry(path, rest, value, builders) {
+        if !self.value.fits(rest) {
+            if rest.is_empty() {
+                // the branch keeps its shape, so its widgets don't flip to the value and back
+                self.own_value = Some(value);
+                path.pop();
+                return Ok(());
+            }
+            self.branch_out(path, context);
+        }
+
+        let hidden = context.hidden;
+        context.hidden |= self.active_index().is_some();
+        let result = self.value.create_entry(path, rest, value, context);
+        context.hidden = hidden;
+        if let Err(error) = result {
             path.pop();
             return Err(error);
         }
 
-        let (widgets, partial_widgets) = Self::run_builders(
+        self.update_widgets();
+
+        // builders that already made a widget keep it up to date through `update_nt`
+        let unbuilt: Vec<_> = context
+            .all_builders()
+            .into_iter()
+            .filter(|builder| !self.widgets.iter().any(|(built, _)| built == builder))
+            .collect();
+        let (widgets, partials) = Self::run_builders(
             &self.key,
             &self.value,
             path,
-            builders,
-            &(0..builders.len())
-                .map(|index| BuilderIndex { index })
-                .collect::<Vec<_>>(),
+            context.leading_slash,
+            context.builders,
+            &unbuilt,
         );
 
-        if !widgets.is_empty() {
-            self.value.strip_widgets();
-        }
-
-        self.widgets.extend(widgets);
-        self.partial_widgets = partial_widgets;
+        self.add_widgets(path, widgets, context);
+        self.set_partials(partials);
 
         path.pop();
 
         Ok(())
     }
 
-    fn strip_widgets(&mut self) {
+    // expects the path to already include this node's key
+    fn add_widgets(
+        &mut self,
+        path: &mut Vec<Key>,
+        widgets: Vec<(BuilderIndex, Widget)>,
+        context: &mut Context<'_>,
+    ) {
+        if widgets.is_empty() {
+            return;
+        }
+        if widgets.iter().any(|(_, widget)| widget.is_observer()) {
+            let path = to_path(context.leading_slash, path);
+            if !context.new_observers.contains(&path) {
+                context.new_observers.push(path.clone());
+            }
+            if !context.observers.contains(&path) {
+                context.observers.push(path);
+            }
+        }
+        let before = self.active_builder();
+        self.widgets.extend(widgets);
+        let builders = context.builders;
+        self.widgets.sort_by_key(|(builder, _)| {
+            (Reverse(builders[builder.index].priority()), builder.index)
+        });
+        self.record_transition(
+            path,
+            before,
+            context.hidden,
+            context.leading_slash,
+            context.changes,
+        );
+    }
+
+    // expects the path to already include this node's key
+    fn record_transition(
+        &self,
+        path: &mut Vec<Key>,
+        before: Option<BuilderIndex>,
+        hidden: bool,
+        leading_slash: bool,
+        changes: &mut Vec<Change>,
+    ) {
+        if hidden {
+            return;
+        }
+        let to_path = |path: &[Key]| to_path(leading_slash, path);
+        match (before, self.active_builder()) {
+            (None, Some(_)) => {
+                let mut covered = Vec::new();
+                self.value.visible_paths(path, leading_slash, &mut covered);
+                changes.extend(covered.into_iter().map(Change::Removed));
+                changes.push(Change::Added(to_path(path)));
+            }
+            (Some(_), None) => {
+                changes.push(Change::Removed(to_path(path)));
+                let mut uncovered = Vec::new();
+                self.value
+                    .visible_paths(path, leading_slash, &mut uncovered);
+                changes.extend(uncovered.into_iter().map(Change::Added));
+            }
+            (Some(before), Some(after)) if before != after => {
+                changes.push(Change::Removed(to_path(path)));
+                changes.push(Change::Added(to_path(path)));
+            }
+            _ => {}
+        }
+    }
+
+    // expects the path to already include this node's key
+    fn cycle(
+        &mut self,
+        path: &mut Vec<Key>,
+        hidden: bool,
+        leading_slash: bool,
+        changes: &mut Vec<Change>,
+    ) {
+        let before = self.active_builder();
+        let can_show_children = matches!(self.value, Value::Branch(_));
+        self.selection = match self.active_index() {
+            Some(index) if index + 1 < self.widgets.len() => {
+                Selection::Builder(self.widgets[index + 1].0)
+            }
+            Some(_) if can_show_children => Selection::Raw,
+            _ => Selection::Default,
+        };
+        self.record_transition(path, before, hidden, leading_slash, changes);
+    }
+
+    // expects the path to already include this node's key
+    fn select_default(
+        &mut self,
+        path: &mut Vec<Key>,
+        hidden: bool,
+        leading_slash: bool,
+        changes: &mut Vec<Change>,
+    ) {
+        let before = self.active_builder();
+        self.selection = Selection::Default;
+        self.record_transition(path, before, hidden, leading_slash, changes);
+    }
+
+    // NT4 allows a topic at the path of a table, and robot code can turn a value into a table
+    // between deploys, so the value is kept alongside the children it now has
+    fn branch_out(&mut self, path: &[Key], context: &mut Context<'_>) {
+        event!(
+            Level::INFO,
+            "{:?} has children as well as a value, rebuilding its widgets as a table",
+            to_path(context.leading_slash, path)
+        );
+        if !context.hidden && self.active_index().is_some() {
+            context
+                .changes
+                .push(Change::Removed(to_path(context.leading_slash, path)));
+        }
         self.widgets.clear();
-        self.value.strip_widgets();
+        self.partial_widgets.clear();
+        self.selection = Selection::Default;
+        let value = std::mem::replace(&mut self.value, Value::Branch(Nodes::default()));
+        self.own_value = value.try_get_value();
     }
 
     fn get<'a>(
@@ -194,14 +460,21 @@ impl Node {
         rest: impl ExactSizeIterator<Item = &'a Key> + fmt::Debug,
     ) -> Option<&Widget> {
         if rest.len() == 0 {
-            return self.widgets.first();
+            return self.active();
+        }
+        // the widget of this node covers all of its children
+        if self.active_index().is_some() {
+            return None;
         }
         self.value.get(rest)
     }
 
     fn get_mut<'a>(&mut self, rest: impl ExactSizeIterator<Item = &'a Key>) -> Option<&mut Widget> {
         if rest.len() == 0 {
-            return self.widgets.first_mut();
+            return self.active_mut();
+        }
+        if self.active_index().is_some() {
+            return None;
         }
         self.value.get_mut(rest)
     }
@@ -228,12 +501,20 @@ impl fmt::Debug for Value {
 }
 
 impl Value {
+    // whether an entry with `rest` keys left after this value can be stored without changing its shape
+    const fn fits(&self, rest: &[Key]) -> bool {
+        matches!(
+            (self, rest),
+            (Self::Leaf(_), []) | (Self::Branch(_), [_, ..])
+        )
+    }
+
     fn update_entry(
         &mut self,
         path: &mut Vec<Key>,
         rest: &[Key],
         value: network_tables::Value,
-        builders: &[Box<dyn Builder>],
+        context: &mut Context<'_>,
     ) -> Result<()> {
         match (self, rest) {
             (Self::Leaf(old_value), []) => {
@@ -245,7 +526,7 @@ impl Value {
             }
             (Self::Branch(_), []) => Err(Error::ExpectedValue(path.clone()).into()),
             (Self::Branch(nodes), [first, rest @ ..]) => {
-                nodes.update_entry(path, first, rest, value, builders)
+                nodes.update_entry(path, first, rest, value, context)
             }
         }
     }
@@ -269,7 +550,7 @@ impl Value {
         path: &mut Vec<Key>,
         rest: &[Key],
         value: network_tables::Value,
-        builders: &[Box<dyn Builder>],
+        context: &mut Context<'_>,
     ) -> Result<()> {
         match (self, rest) {
             (Self::Leaf(old_value), []) => {
@@ -281,14 +562,15 @@ impl Value {
             }
             (Self::Branch(_), []) => Err(Error::ExpectedValue(path.clone()).into()),
             (Self::Branch(nodes), [first, tail @ ..]) => {
-                nodes.create_entry(path, first, tail, value, builders)
+                nodes.create_entry(path, first, tail, value, context)
             }
         }
     }
 
-    fn strip_widgets(&mut self) {
+    // expects the path to already include this value's key
+    fn visible_paths(&self, path: &mut Vec<Key>, leading_slash: bool, paths: &mut Vec<Path>) {
         if let Self::Branch(nodes) = self {
-            nodes.strip_widgets();
+            nodes.visible_paths(path, leading_slash, paths);
         }
     }
 
@@ -321,24 +603,56 @@ impl Value {
     }
 }
 
+#[cfg(test)]
+impl Value {
+    /// A branch of the entries at the keys separated by `/`, like a sendable's table, for testing builders.
+    pub fn branch(entries: Vec<(&str, network_tables::Value)>) -> Self {
+        let mut nodes = Nodes::default();
+        let (mut changes, mut observers, mut new_observers) = (Vec::new(), Vec::new(), Vec::new());
+        let mut context = Context {
+            builders: &[],
+            changes: &mut changes,
+            observers: &mut observers,
+            new_observers: &mut new_observers,
+            hidden: false,
+            leading_slash: true,
+        };
+        for (keys, value) in entries {
+            let keys: Vec<_> = keys.split('/').map(Key::from).collect();
+            nodes
+                .create_entry(&mut Vec::new(), &keys[0], &keys[1..], value, &mut context)
+                .unwrap();
+        }
+        Self::Branch(nodes)
+    }
+}
+
+// nodes are kept in insertion order, with an index from each key to its position
 #[derive(Default)]
 pub struct Nodes {
     nodes: Vec<Node>,
+    index: HashMap<Key, usize>,
 }
 
 impl Nodes {
+    fn node(&self, key: &Key) -> Option<&Node> {
+        self.index.get(key).map(|&index| &self.nodes[index])
+    }
+
+    fn node_mut(&mut self, key: &Key) -> Option<&mut Node> {
+        self.index.get(key).map(|&index| &mut self.nodes[index])
+    }
+
     fn update_entry(
         &mut self,
         path: &mut Vec<Key>,
         first: &Key,
         rest: &[Key],
         value: network_tables::Value,
-        builders: &[Box<dyn Builder>],
+        context: &mut Context<'_>,
     ) -> Result<()> {
-        for node in &mut self.nodes {
-            if &node.key == first {
-                return node.update_entry(path, rest, value, builders);
-            }
+        if let Some(node) = self.node_mut(first) {
+            return node.update_entry(path, rest, value, context);
         }
         Err(Error::NoSuchEntry(path.clone(), first.to_string(), rest.to_vec()).into())
     }
@@ -349,14 +663,13 @@ impl Nodes {
         first: &Key,
         rest: &[Key],
         value: network_tables::Value,
-        builders: &[Box<dyn Builder>],
+        context: &mut Context<'_>,
     ) -> Result<()> {
-        for node in &mut self.nodes {
-            if &node.key == first {
-                return node.create_entry(path, rest, value, builders);
-            }
+        if let Some(node) = self.node_mut(first) {
+            return node.create_entry(path, rest, value, context);
         }
-        let node = Node::from_entry(path, first, rest, value, builders)?;
+        let node = Node::from_entry(path, first, rest, value, context)?;
+        self.index.insert(first.clone(), self.nodes.len());
         self.nodes.push(node);
         Ok(())
     }
@@ -368,20 +681,75 @@ impl Nodes {
     }
 
     pub fn try_get_value(&self, key: impl Into<Key>) -> Option<network_tables::Value> {
-        let key = key.into();
+        self.node(&key.into())?.value.try_get_value()
+    }
+
+    fn leaves(
+        &self,
+        path: &mut Vec<Key>,
+        leading_slash: bool,
+        leaves: &mut Vec<(Path, network_tables::Value)>,
+    ) {
         for node in &self.nodes {
-            if node.key == key {
-                if let Value::Leaf(value) = &node.value {
-                    return Some(value.clone());
+            path.push(node.key.clone());
+            match &node.value {
+                Value::Leaf(value) => leaves.push((to_path(leading_slash, path), value.clone())),
+                Value::Branch(nodes) => {
+                    if let Some(value) = &node.own_value {
+                        leaves.push((to_path(leading_slash, path), value.clone()));
+                    }
+                    nodes.leaves(path, leading_slash, leaves);
                 }
             }
+            path.pop();
         }
-        None
     }
 
-    fn strip_widgets(&mut self) {
-        for node in &mut self.nodes {
-            node.strip_widgets();
+    // the paths of the widgets that would be shown if nothing above these nodes had a widget
+    fn visible_paths(&self, path: &mut Vec<Key>, leading_slash: bool, paths: &mut Vec<Path>) {
+        for node in &self.nodes {
+            path.push(node.key.clone());
+            if node.active_index().is_some() {
+                paths.push(to_path(leading_slash, path));
+            } else {
+                node.value.visible_paths(path, leading_slash, paths);
+            }
+            path.pop();
+        }
+    }
+
+    // walks to the node at the given keys, also returning whether an ancestor's widget covers it
+    fn node_at_mut(&mut self, keys: &[Key]) -> Option<(&mut Node, bool)> {
+        let (first, rest) = keys.split_first()?;
+        let node = self.node_mut(first)?;
+        if rest.is_empty() {
+            return Some((node, false));
+        }
+        let covered = node.active_index().is_some();
+        let Value::Branch(nodes) = &mut node.value else {
+            return None;
+        };
+        let (node, hidden) = nodes.node_at_mut(rest)?;
+        Some((node, hidden || covered))
+    }
+
+    fn diagnostics<'a>(
+        &'a self,
+        path: &mut Vec<Key>,
+        leading_slash: bool,
+        diagnostics: &mut Vec<Diagnostic<'a>>,
+    ) {
+        for node in &self.nodes {
+            path.push(node.key.clone());
+            diagnostics.extend(node.partial_widgets.iter().map(|partial| Diagnostic {
+                path: to_path(leading_slash, path),
+                error: &partial.error,
+                since: partial.since,
+            }));
+            if let Value::Branch(nodes) = &node.value {
+                nodes.diagnostics(path, leading_slash, diagnostics);
+            }
+            path.pop();
         }
     }
 
@@ -394,12 +762,7 @@ impl Nodes {
         first: &Key,
         rest: impl ExactSizeIterator<Item = &'a Key> + fmt::Debug,
     ) -> Option<&Widget> {
-        for node in &self.nodes {
-            if &node.key == first {
-                return node.get(rest);
-            }
-        }
-        None
+        self.node(first)?.get(rest)
     }
 
     fn get_mut<'a>(
@@ -407,12 +770,7 @@ impl Nodes {
         first: &Key,
         rest: impl ExactSizeIterator<Item = &'a Key>,
     ) -> Option<&mut Widget> {
-        for node in &mut self.nodes {
-            if &node.key == first {
-                return node.get_mut(rest);
-            }
-        }
-        None
+        self.node_mut(first)?.get_mut(rest)
     }
 }
 
@@ -432,6 +790,12 @@ pub struct Tree {
     // represented as a boxed array rather than a vector to emphasize the fact that our builder indicies aren't protected by the type system
     builders: Box<[Box<dyn widgets::Builder>]>,
     nodes: Nodes,
+    // topics without a leading `/`, like `$sys/clients`, kept apart so they don't merge with `/$sys/clients`
+    unslashed: Nodes,
+    changes: Vec<Change>,
+    // the nodes with widgets that watch entries elsewhere in the tree
+    observers: Vec<Path>,
+    new_observers: Vec<Path>,
 }
 
 impl Tree {
@@ -440,48 +804,340 @@ impl Tree {
         Self {
             builders: builders_vec.into_boxed_slice(),
             nodes: Nodes::default(),
+            unslashed: Nodes::default(),
+            changes: Vec::new(),
+            observers: Vec::new(),
+            new_observers: Vec::new(),
+        }
+    }
+
+    fn context(&mut self, leading_slash: bool) -> (&mut Nodes, Context<'_>) {
+        (
+            if leading_slash {
+                &mut self.nodes
+            } else {
+                &mut self.unslashed
+            },
+            Context {
+                builders: &self.builders,
+                changes: &mut self.changes,
+                observers: &mut self.observers,
+                new_observers: &mut self.new_observers,
+                hidden: false,
+                leading_slash,
+            },
+        )
+    }
+
+    /// The nodes of the topics with and without a leading `/`.
+    pub const fn roots(&self) -> [(bool, &Nodes); 2] {
+        [(true, &self.nodes), (false, &self.unslashed)]
+    }
+
+    const fn root(&self, leading_slash: bool) -> &Nodes {
+        if leading_slash {
+            &self.nodes
+        } else {
+            &self.unslashed
+        }
+    }
+
+    const fn root_mut(&mut self, leading_slash: bool) -> &mut Nodes {
+        if leading_slash {
+            &mut self.nodes
+        } else {
+            &mut self.unslashed
         }
     }
 
     pub fn update_entry(&mut self, entry: Entry) -> Result<()> {
-        self.nodes.update_entry(
+        let observed = self.observed(&entry);
+        let (nodes, mut context) = self.context(entry.path.leading_slash);
+        nodes.update_entry(
             &mut Vec::new(),
             &entry.path.first,
             &entry.path.rest,
             entry.value,
-            &self.builders,
-        )
+            &mut context,
+        )?;
+        let new_observers = std::mem::take(&mut self.new_observers);
+        self.notify_observers(observed, &new_observers);
+        self.seed_observers(&new_observers);
+        Ok(())
     }
 
     pub fn create_entry(&mut self, entry: Entry) -> Result<()> {
-        self.nodes.create_entry(
+        let observed = self.observed(&entry);
+        let (nodes, mut context) = self.context(entry.path.leading_slash);
+        nodes.create_entry(
             &mut Vec::new(),
             &entry.path.first,
             &entry.path.rest,
             entry.value,
-            &self.builders,
-        )
+            &mut context,
+        )?;
+        let new_observers = std::mem::take(&mut self.new_observers);
+        self.notify_observers(observed, &new_observers);
+        self.seed_observers(&new_observers);
+        Ok(())
+    }
+
+    // only copies the entry when something could be watching it
+    fn observed(&self, entry: &Entry) -> Option<Entry> {
+        if self.observers.is_empty() {
+            None
+        } else {
+            Some(entry.clone())
+        }
+    }
+
+    // new observers are skipped, since they're about to see every entry including this one
+    fn notify_observers(&mut self, entry: Option<Entry>, new_observers: &[Path]) {
+        let Some(entry) = entry else {
+            return;
+        };
+        for observer in &self.observers {
+            if new_observers.contains(observer) {
+                continue;
+            }
+            let keys = observer.clone().into_vec();
+            let root = if observer.leading_slash {
+                &mut self.nodes
+            } else {
+                &mut self.unslashed
+            };
+            if let Some((node, _)) = root.node_at_mut(&keys) {
+                for (_, widget) in &mut node.widgets {
+                    widget.update_observed(&entry.path, &entry.value);
+                }
+            }
+        }
+    }
+
+    // shows new observers every entry that was already in the tree, since they only hear about
+    // entries as they change
+    fn seed_observers(&mut self, new_observers: &[Path]) {
+        if new_observers.is_empty() {
+            return;
+        }
+        let mut leaves = Vec::new();
+        for (leading_slash, nodes) in self.roots() {
+            nodes.leaves(&mut Vec::new(), leading_slash, &mut leaves);
+        }
+        for observer in new_observers {
+            let keys = observer.clone().into_vec();
+            if let Some((node, _)) = self.root_mut(observer.leading_slash).node_at_mut(&keys) {
+                for (_, widget) in &mut node.widgets {
+                    for (path, value) in &leaves {
+                        widget.update_observed(path, value);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Switch the node at the path to its next widget, or to showing its children after the last one.
+    pub fn cycle(&mut self, path: &Path) {
+        let mut keys = path.clone().into_vec();
+        let root = if path.leading_slash {
+            &mut self.nodes
+        } else {
+            &mut self.unslashed
+        };
+        if let Some((node, hidden)) = root.node_at_mut(&keys) {
+            node.cycle(&mut keys, hidden, path.leading_slash, &mut self.changes);
+        }
+    }
+
+    /// Go back to showing the widget of the closest ancestor that was switched to showing its children.
+    pub fn collapse(&mut self, path: &Path) {
+        let mut keys = path.clone().into_vec();
+        let root = if path.leading_slash {
+            &mut self.nodes
+        } else {
+            &mut self.unslashed
+        };
+        while keys.len() > 1 {
+            keys.pop();
+            let Some((node, hidden)) = root.node_at_mut(&keys) else {
+                continue;
+            };
+            if node.selection == Selection::Raw && !node.widgets.is_empty() {
+                node.select_default(&mut keys, hidden, path.leading_slash, &mut self.changes);
+                return;
+            }
+        }
+    }
+
+    /// Take the changes to which paths have widgets since the last call.
+    pub fn drain_changes(&mut self) -> Vec<Change> {
+        std::mem::take(&mut self.changes)
     }
 
     pub fn widgets(&self) -> Vec<&Widget> {
         let mut widgets = Vec::new();
-        for node in &self.nodes.nodes {
-            node.widgets(&mut widgets);
+        for (_, nodes) in self.roots() {
+            nodes.widgets(&mut widgets);
         }
         widgets
     }
 
+    /// Every node that a builder couldn't finish a widget for, oldest first.
+    pub fn diagnostics(&self) -> Vec<Diagnostic<'_>> {
+        let mut diagnostics = Vec::new();
+        for (leading_slash, nodes) in self.roots() {
+            nodes.diagnostics(&mut Vec::new(), leading_slash, &mut diagnostics);
+        }
+        diagnostics.sort_by_key(|diagnostic| diagnostic.since);
+        diagnostics
+    }
+
     pub fn get(&self, path: &Path) -> Option<&Widget> {
-        self.nodes.get(&path.first, path.rest.iter())
+        self.root(path.leading_slash)
+            .get(&path.first, path.rest.iter())
     }
 
     pub fn get_mut(&mut self, path: &Path) -> Option<&mut Widget> {
-        self.nodes.get_mut(&path.first, path.rest.iter())
+        self.root_mut(path.leading_slash)
+            .get_mut(&path.first, path.rest.iter())
     }
 }
 
 impl fmt::Debug for Tree {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.nodes.fmt(f)
+        self.nodes.fmt(f)?;
+        if !self.unslashed.nodes.is_empty() {
+            self.unslashed.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use network_tables::Value;
+
+    use super::{Change, Tree};
+    use crate::{
+        backend::{Entry, Path},
+        widgets,
+    };
+
+    fn tree() -> Tree {
+        Tree::new(widgets::builders())
+    }
+
+    fn entry(topic: &str, value: Value) -> Entry {
+        Entry {
+            path: topic.parse().unwrap(),
+            value,
+        }
+    }
+
+    fn path(topic: &str) -> Path {
+        topic.parse().unwrap()
+    }
+
+    #[test]
+    fn topics_without_a_leading_slash_stay_apart() {
+        let mut tree = tree();
+        tree.create_entry(entry("/x/y", Value::F64(1.0))).unwrap();
+        tree.create_entry(entry("x/y", Value::F64(2.0))).unwrap();
+
+        for topic in ["/x/y", "x/y"] {
+            let widget = tree.get(&path(topic)).unwrap();
+            assert_eq!(widget.title.to_string(), topic);
+        }
+        let added: Vec<_> = tree
+            .drain_changes()
+            .into_iter()
+            .filter_map(|change| match change {
+                Change::Added(path) => Some(path.to_string()),
+                Change::Removed(_) => None,
+            })
+            .collect();
+        assert_eq!(added, ["/x/y", "x/y"]);
+    }
+
+    #[test]
+    fn a_value_and_a_table_at_the_same_path_dont_flip() {
+        let mut tree = tree();
+        tree.create_entry(entry("/a", Value::F64(1.0))).unwrap();
+        tree.create_entry(entry("/a/b", Value::F64(2.0))).unwrap();
+        tree.drain_changes();
+
+        for tick in 0..3 {
+            tree.update_entry(entry("/a", Value::F64(f64::from(tick))))
+                .unwrap();
+            tree.update_entry(entry("/a/b", Value::F64(2.0))).unwrap();
+            assert!(tree.drain_changes().is_empty());
+        }
+        assert!(tree.get(&path("/a/b")).is_some());
+        let [(_, nodes), _] = tree.roots();
+        let a = nodes.iter().next().unwrap();
+        assert_eq!(a.own_value, Some(Value::F64(2.0)));
+    }
+
+    fn changes(tree: &mut Tree) -> (Vec<String>, Vec<String>) {
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        for change in tree.drain_changes() {
+            match change {
+                Change::Added(path) => added.push(path.to_string()),
+                Change::Removed(path) => removed.push(path.to_string()),
+            }
+        }
+        (added, removed)
+    }
+
+    #[test]
+    fn a_parent_gaining_a_widget_covers_its_children_until_cycled() {
+        let mut tree = tree();
+        tree.create_entry(entry("/SmartDashboard/gyro/Value", Value::F64(90.0)))
+            .unwrap();
+        let (added, _) = changes(&mut tree);
+        assert_eq!(added, ["/SmartDashboard/gyro/Value"]);
+
+        tree.create_entry(entry("/SmartDashboard/gyro/.type", Value::from("Gyro")))
+            .unwrap();
+        let (added, removed) = changes(&mut tree);
+        assert_eq!(added, ["/SmartDashboard/gyro"]);
+        assert_eq!(removed, ["/SmartDashboard/gyro/Value"]);
+        assert!(tree.get(&path("/SmartDashboard/gyro/Value")).is_none());
+
+        // cycling past the last of the parent's widgets shows the children again
+        let gyro = path("/SmartDashboard/gyro");
+        let mut cycles = 0;
+        let (added, removed) = loop {
+            tree.cycle(&gyro);
+            let (added, removed) = changes(&mut tree);
+            if !added.contains(&gyro.to_string()) {
+                break (added, removed);
+            }
+            cycles += 1;
+            assert!(cycles < 20, "never cycled to the children");
+        };
+        assert!(added.contains(&String::from("/SmartDashboard/gyro/Value")));
+        assert_eq!(removed, ["/SmartDashboard/gyro"]);
+        assert!(tree.get(&path("/SmartDashboard/gyro/Value")).is_some());
+
+        tree.collapse(&path("/SmartDashboard/gyro/Value"));
+        let (added, removed) = changes(&mut tree);
+        assert_eq!(added, ["/SmartDashboard/gyro"]);
+        assert!(removed.contains(&String::from("/SmartDashboard/gyro/Value")));
+    }
+
+    #[test]
+    fn widgets_under_a_covered_parent_record_no_changes() {
+        let mut tree = tree();
+        tree.create_entry(entry("/SmartDashboard/gyro/.type", Value::from("Gyro")))
+            .unwrap();
+        tree.create_entry(entry("/SmartDashboard/gyro/Value", Value::F64(90.0)))
+            .unwrap();
+        tree.drain_changes();
+
+        tree.create_entry(entry("/SmartDashboard/gyro/Rate", Value::F64(1.0)))
+            .unwrap();
+        assert!(tree.drain_changes().is_empty());
     }
 }
@@ -0,0 +1,190 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    prelude::{Buffer, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget, Wrap},
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    util::{self, NTValue},
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+// how long the border flashes for after a new error, and how fast
+const FLASH_DURATION: Duration = Duration::from_secs(3);
+const FLASH_PERIOD: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not Alerts")]
+    NotAlerts,
+    #[error("{0} isn't an array of strings")]
+    NotStrings(&'static str),
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    const fn key(self) -> &'static str {
+        match self {
+            Self::Error => "errors",
+            Self::Warning => "warnings",
+            Self::Info => "infos",
+        }
+    }
+
+    const fn icon(self) -> &'static str {
+        match self {
+            Self::Error => "✖",
+            Self::Warning => "⚠",
+            Self::Info => "ℹ",
+        }
+    }
+
+    const fn color(self) -> Color {
+        match self {
+            Self::Error => Color::Red,
+            Self::Warning => Color::Yellow,
+            Self::Info => Color::Cyan,
+        }
+    }
+}
+
+/// `WPILib` persistent alerts, most severe first.
+#[derive(Clone, Debug)]
+pub struct Alerts {
+    /// sorted by severity, in the order the robot published them otherwise
+    alerts: Vec<(Severity, String)>,
+    new_error_at: Option<Instant>,
+}
+
+impl Alerts {
+    fn parse(value: &Value) -> Result<Self, Error> {
+        let nodes = util::sendable(value, "Alerts").ok_or(Error::NotAlerts)?;
+        let mut alerts = Vec::new();
+        for severity in [Severity::Error, Severity::Warning, Severity::Info] {
+            // a robot without any alerts of a severity may not publish it
+            let Some(texts) = nodes.try_get_value(severity.key()) else {
+                continue;
+            };
+            let texts = texts
+                .try_to_string_array()
+                .ok_or_else(|| Error::NotStrings(severity.key()))?;
+            alerts.extend(texts.into_iter().map(|text| (severity, text)));
+        }
+        Ok(Self {
+            alerts,
+            new_error_at: None,
+        })
+    }
+
+    fn errors(&self) -> impl Iterator<Item = &str> {
+        self.alerts
+            .iter()
+            .filter(|(severity, _)| matches!(severity, Severity::Error))
+            .map(|(_, text)| text.as_str())
+    }
+}
+
+impl Kind for Alerts {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let lines: Vec<_> = self
+            .alerts
+            .iter()
+            .map(|(severity, text)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", severity.icon()),
+                        Style::default().fg(severity.color()),
+                    ),
+                    Span::raw(text.as_str()),
+                ])
+            })
+            .collect();
+        if lines.is_empty() {
+            Paragraph::new("no alerts")
+                .style(Style::default().fg(Color::DarkGray))
+                .render(area, buf);
+            return;
+        }
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .render(area, buf);
+    }
+
+    fn prompt(&self) -> String {
+        String::from("Alerts are constant")
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        let Ok(mut alerts) = Self::parse(value) else {
+            return;
+        };
+        let has_new_error = alerts
+            .errors()
+            .any(|error| !self.errors().any(|old| old == error));
+        alerts.new_error_at = if has_new_error {
+            Some(Instant::now())
+        } else {
+            self.new_error_at
+        };
+        *self = alerts;
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 2,
+            height: 2,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+
+    fn border_color(&self) -> Option<Color> {
+        let elapsed = self.new_error_at?.elapsed();
+        let is_lit = (elapsed.as_millis() / FLASH_PERIOD.as_millis()).is_multiple_of(2);
+        (elapsed < FLASH_DURATION && is_lit).then_some(Color::Red)
+    }
+}
+
+pub struct Builder;
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match Alerts::parse(value) {
+            Ok(alerts) => BuildResult::Complete(Box::new(alerts)),
+            Err(Error::NotAlerts) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::{Alignment, Buffer, Rect},
+    style::{Color, Style},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::{
+    backend::{Entry, Key, Path, Write},
+    state::add::EntryType,
+    widget_tree::Value,
+};
+
+use super::{
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+/// How boolean boxes look and what pressing them does.
+#[derive(Clone, Debug)]
+pub struct Settings {
+    pub true_color: Color,
+    pub false_color: Color,
+    pub true_label: String,
+    pub false_label: String,
+    /// write true and then false once the duration has passed, instead of toggling
+    pub momentary: Option<Duration>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            true_color: Color::Green,
+            false_color: Color::Red,
+            true_label: String::from("true"),
+            false_label: String::from("false"),
+            momentary: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BooleanBox {
+    value: bool,
+    settings: Settings,
+    error: Option<String>,
+    is_finished: bool,
+}
+
+impl BooleanBox {
+    fn press(&mut self, path: &Path) -> Write {
+        let entry = |value: bool| Entry {
+            path: path.clone(),
+            value: network_tables::Value::from(value),
+        };
+        if let Some(duration) = self.settings.momentary {
+            self.value = true;
+            return Write::one(entry(true)).then_after(duration, entry(false));
+        }
+        self.value = !self.value;
+        Write::one(entry(self.value))
+    }
+}
+
+impl Kind for BooleanBox {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let (color, label) = if self.value {
+            (self.settings.true_color, &self.settings.true_label)
+        } else {
+            (self.settings.false_color, &self.settings.false_label)
+        };
+        let style = Style::default().bg(color).fg(Color::Black);
+        Block::default().style(style).render(area, buf);
+        // center the label vertically as well
+        let middle = Rect {
+            y: area.y + area.height / 2,
+            height: area.height.min(1),
+            ..area
+        };
+        Paragraph::new(label.as_str())
+            .style(style)
+            .alignment(Alignment::Center)
+            .render(middle, buf);
+    }
+
+    fn prompt(&self) -> String {
+        let prompt = "Enter true or false, or press Enter or space on the box to toggle it";
+        let Some(error) = &self.error else {
+            return prompt.to_string();
+        };
+        format!("{error}. {prompt}")
+    }
+
+    fn update(&mut self, path: &Path, text: &str) -> Write {
+        match EntryType::Boolean.parse(text) {
+            Ok(value) => {
+                self.value = value.as_bool().unwrap_or_default();
+                self.error = None;
+                self.is_finished = true;
+                Write::one(Entry {
+                    path: path.clone(),
+                    value,
+                })
+            }
+            Err(error) => {
+                self.error = Some(error.to_string());
+                Write::default()
+            }
+        }
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Value::Leaf(network_tables::Value::Boolean(value)) = value {
+            self.value = *value;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.error = None;
+        self.is_finished = false;
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 1,
+            height: 1,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+
+    fn handle_key(&mut self, path: &Path, code: KeyCode) -> Option<Write> {
+        match code {
+            KeyCode::Enter | KeyCode::Char(' ') => Some(self.press(path)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Builder {
+    pub settings: Settings,
+}
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, key: &Key, value: &Value) -> BuildResult {
+        let Value::Leaf(network_tables::Value::Boolean(value)) = value else {
+            return BuildResult::None;
+        };
+        if key.as_str().starts_with('.') {
+            return BuildResult::None;
+        }
+        BuildResult::Complete(Box::new(BooleanBox {
+            value: *value,
+            settings: self.settings.clone(),
+            error: None,
+            is_finished: false,
+        }))
+    }
+
+    fn priority(&self) -> i32 {
+        5
+    }
+}
@@ -0,0 +1,153 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::{Alignment, Buffer, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Paragraph, Widget},
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Entry, Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    util::{self, NTValue},
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a Command")]
+    NotCommand,
+    #[error("missing .name")]
+    MissingName,
+    #[error("missing running")]
+    MissingRunning,
+}
+
+/// A command-based `Command`, which is started and canceled by writing whether it's running.
+#[derive(Clone, Debug)]
+pub struct Command {
+    name: String,
+    running: bool,
+    /// commands in a group are run by the group, so they can't be started on their own
+    is_parented: bool,
+}
+
+impl Command {
+    fn parse(value: &Value) -> Result<Self, Error> {
+        let nodes = util::sendable(value, "Command").ok_or(Error::NotCommand)?;
+        let name = nodes
+            .try_get_value(".name")
+            .and_then(|value| value.try_to_string())
+            .ok_or(Error::MissingName)?;
+        let running = nodes
+            .try_get_value("running")
+            .and_then(|value| value.as_bool())
+            .ok_or(Error::MissingRunning)?;
+        let is_parented = nodes
+            .try_get_value(".isParented")
+            .and_then(|value| value.as_bool())
+            .unwrap_or_default();
+        Ok(Self {
+            name,
+            running,
+            is_parented,
+        })
+    }
+}
+
+impl Kind for Command {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let (color, state) = if self.running {
+            (Color::Green, "running")
+        } else {
+            (Color::DarkGray, "stopped")
+        };
+        let style = Style::default().bg(color).fg(Color::Black);
+        Block::default().style(style).render(area, buf);
+        let mut lines = vec![Line::from(self.name.as_str()), Line::from(state)];
+        if self.is_parented {
+            lines.push(Line::from("in a group"));
+        }
+        // center the lines vertically as well
+        #[allow(clippy::cast_possible_truncation)]
+        let height = (lines.len() as u16).min(area.height);
+        let middle = Rect {
+            y: area.y + (area.height - height) / 2,
+            height,
+            ..area
+        };
+        Paragraph::new(lines)
+            .style(style)
+            .alignment(Alignment::Center)
+            .render(middle, buf);
+    }
+
+    fn prompt(&self) -> String {
+        if self.is_parented {
+            String::from("This command is run by its group, so it can't be started or canceled")
+        } else {
+            String::from("Press Enter or space on the command to start or cancel it")
+        }
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Ok(command) = Self::parse(value) {
+            *self = command;
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 1,
+            height: 1,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+
+    fn handle_key(&mut self, path: &Path, code: KeyCode) -> Option<Write> {
+        if self.is_parented || !matches!(code, KeyCode::Enter | KeyCode::Char(' ')) {
+            return None;
+        }
+        self.running = !self.running;
+        let mut path = path.clone();
+        path.push("running");
+        Some(Write::one(Entry {
+            path,
+            value: network_tables::Value::from(self.running),
+        }))
+    }
+}
+
+pub struct Builder;
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match Command::parse(value) {
+            Ok(command) => BuildResult::Complete(Box::new(command)),
+            Err(Error::NotCommand) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
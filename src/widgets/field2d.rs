@@ -0,0 +1,255 @@
+use ratatui::{
+    prelude::{Buffer, Rect},
+    style::{Color, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{
+        canvas::{Canvas, Context, Line as CanvasLine, Points, Rectangle},
+        Widget,
+    },
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    util::{self, NTValue},
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+const OBJECT_COLORS: [Color; 5] = [
+    Color::Yellow,
+    Color::Magenta,
+    Color::Green,
+    Color::Red,
+    Color::Blue,
+];
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a Field2d")]
+    NotField2d,
+    #[error("missing Robot")]
+    MissingRobot,
+    #[error("{0} isn't an array of x, y and rotation triples")]
+    NotPoses(String),
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Pose {
+    x: f64,
+    y: f64,
+    /// in degrees, counterclockwise
+    rotation: f64,
+}
+
+fn to_poses(name: &str, value: &network_tables::Value) -> Result<Vec<Pose>, Error> {
+    let numbers = value
+        .try_to_f64_array()
+        .filter(|numbers| numbers.len() % 3 == 0)
+        .ok_or_else(|| Error::NotPoses(name.to_string()))?;
+    Ok(numbers
+        .chunks_exact(3)
+        .map(|pose| Pose {
+            x: pose[0],
+            y: pose[1],
+            rotation: pose[2],
+        })
+        .collect())
+}
+
+/// The size of the field in meters, which changes from season to season.
+#[derive(Copy, Clone, Debug)]
+pub struct Settings {
+    pub width: f64,
+    pub height: f64,
+    pub robot_size: f64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            width: 16.54,
+            height: 8.21,
+            robot_size: 0.9,
+        }
+    }
+}
+
+/// The robot and other objects of a `WPILib` `Field2d`, like trajectories and vision targets.
+#[derive(Clone, Debug)]
+pub struct Field2d {
+    robot: Vec<Pose>,
+    objects: Vec<(String, Vec<Pose>)>,
+    settings: Settings,
+}
+
+impl Field2d {
+    fn parse(value: &Value, settings: Settings) -> Result<Self, Error> {
+        let nodes = util::sendable(value, "Field2d").ok_or(Error::NotField2d)?;
+        let robot = nodes.try_get_value("Robot").ok_or(Error::MissingRobot)?;
+        let robot = to_poses("Robot", &robot)?;
+        let mut objects = Vec::new();
+        for node in nodes.iter() {
+            let name = node.key.as_str();
+            if name.starts_with('.') || name == "Robot" {
+                continue;
+            }
+            if let Some(value) = node.value.try_get_value() {
+                objects.push((name.to_string(), to_poses(name, &value)?));
+            }
+        }
+        Ok(Self {
+            robot,
+            objects,
+            settings,
+        })
+    }
+
+    fn draw_robot(&self, ctx: &mut Context<'_>, pose: Pose) {
+        let half = self.settings.robot_size / 2.0;
+        let (sin, cos) = pose.rotation.to_radians().sin_cos();
+        let corner = |forward: f64, left: f64| {
+            (
+                left.mul_add(-sin, forward.mul_add(cos, pose.x)),
+                left.mul_add(cos, forward.mul_add(sin, pose.y)),
+            )
+        };
+        let corners = [
+            corner(half, half),
+            corner(-half, half),
+            corner(-half, -half),
+            corner(half, -half),
+        ];
+        for (index, (x1, y1)) in corners.iter().enumerate() {
+            let (x2, y2) = corners[(index + 1) % corners.len()];
+            ctx.draw(&CanvasLine {
+                x1: *x1,
+                y1: *y1,
+                x2,
+                y2,
+                color: Color::Cyan,
+            });
+        }
+        // the heading, from the center to the middle of the front
+        let (x2, y2) = corner(half, 0.0);
+        ctx.draw(&CanvasLine {
+            x1: pose.x,
+            y1: pose.y,
+            x2,
+            y2,
+            color: Color::Cyan,
+        });
+    }
+}
+
+fn draw_object(ctx: &mut Context<'_>, poses: &[Pose], color: Color) {
+    // several poses are a trajectory, so they're joined up
+    if poses.len() > 1 {
+        for pair in poses.windows(2) {
+            ctx.draw(&CanvasLine {
+                x1: pair[0].x,
+                y1: pair[0].y,
+                x2: pair[1].x,
+                y2: pair[1].y,
+                color,
+            });
+        }
+    } else {
+        let coords: Vec<_> = poses.iter().map(|pose| (pose.x, pose.y)).collect();
+        ctx.draw(&Points {
+            coords: &coords,
+            color,
+        });
+    }
+}
+
+impl Kind for Field2d {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let Settings { width, height, .. } = self.settings;
+        Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([0.0, width])
+            .y_bounds([0.0, height])
+            .paint(|ctx| {
+                ctx.draw(&Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width,
+                    height,
+                    color: Color::DarkGray,
+                });
+                for ((_, poses), color) in self.objects.iter().zip(OBJECT_COLORS.iter().cycle()) {
+                    draw_object(ctx, poses, *color);
+                }
+                for pose in &self.robot {
+                    self.draw_robot(ctx, *pose);
+                }
+                // names of the objects in the top left, in their colors
+                let legend: Vec<_> = self
+                    .objects
+                    .iter()
+                    .zip(OBJECT_COLORS.iter().cycle())
+                    .map(|((name, _), color)| {
+                        Span::styled(format!("{name} "), Style::default().fg(*color))
+                    })
+                    .collect();
+                ctx.print(0.0, height, Line::from(legend));
+            })
+            .render(area, buf);
+    }
+
+    fn prompt(&self) -> String {
+        String::from("Fields are constant")
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Ok(field) = Self::parse(value, self.settings) {
+            *self = field;
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 3,
+            height: 2,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Default)]
+pub struct Builder {
+    pub settings: Settings,
+}
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match Field2d::parse(value, self.settings) {
+            Ok(field) => BuildResult::Complete(Box::new(field)),
+            Err(Error::NotField2d) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
@@ -0,0 +1,236 @@
+use std::{f64::consts::PI, str::FromStr};
+
+use ratatui::{
+    prelude::{Alignment, Buffer, Rect},
+    style::{Color, Style},
+    symbols::Marker,
+    text::Line,
+    widgets::{
+        canvas::{Canvas, Line as CanvasLine, Points},
+        Block, Gauge as UIGauge, Paragraph, Widget,
+    },
+};
+
+use crate::{
+    backend::{Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    graph::format_number,
+    util::NTValue,
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+#[derive(Copy, Clone, Debug)]
+pub enum Shape {
+    Bar,
+    VerticalBar,
+    Dial,
+}
+
+/// Colors for values at or above each threshold, written like `10:red,11.5:yellow,12.5:green`.
+#[derive(Clone, Debug, Default)]
+pub struct Thresholds(Vec<(f64, Color)>);
+
+impl FromStr for Thresholds {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut thresholds = s
+            .split(',')
+            .map(|threshold| {
+                let (value, color) = threshold.split_once(':').ok_or(())?;
+                let value = value.trim().parse().map_err(|_| ())?;
+                let color = color.trim().parse().map_err(|_| ())?;
+                Ok((value, color))
+            })
+            .collect::<Result<Vec<(f64, Color)>, ()>>()?;
+        thresholds.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Ok(Self(thresholds))
+    }
+}
+
+impl Thresholds {
+    fn color(&self, value: f64) -> Color {
+        self.0
+            .iter()
+            .rev()
+            .find(|(threshold, _)| value >= *threshold)
+            .or_else(|| self.0.first())
+            .map_or(Color::Green, |(_, color)| *color)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Settings {
+    pub shape: Shape,
+    pub min: f64,
+    pub max: f64,
+    pub thresholds: Thresholds,
+    pub unit: String,
+}
+
+/// A number within a range, drawn as a bar or a dial.
+#[derive(Clone, Debug)]
+pub struct Gauge {
+    value: f64,
+    settings: Settings,
+}
+
+impl Gauge {
+    // every shape draws from this, which is always within 0 to 1 even for a NaN value
+    fn ratio(&self) -> f64 {
+        let ratio = (self.value - self.settings.min) / (self.settings.max - self.settings.min);
+        if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        }
+    }
+
+    fn label(&self) -> String {
+        let value = format_number(self.value);
+        if self.settings.unit.is_empty() {
+            value
+        } else {
+            format!("{value} {}", self.settings.unit)
+        }
+    }
+
+    fn render_vertical_bar(&self, area: Rect, buf: &mut Buffer, color: Color) {
+        let filled = (self.ratio() * f64::from(area.height)).round();
+        // the ratio is clamped, so the filled rows always fit within the area
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let filled = filled as u16;
+        let bar = Rect {
+            y: area.y + area.height - filled,
+            height: filled,
+            ..area
+        };
+        Block::default()
+            .style(Style::default().bg(color))
+            .render(bar, buf);
+        let middle = Rect {
+            y: area.y + area.height / 2,
+            height: area.height.min(1),
+            ..area
+        };
+        Paragraph::new(self.label())
+            .alignment(Alignment::Center)
+            .render(middle, buf);
+    }
+
+    fn render_dial(&self, area: Rect, buf: &mut Buffer, color: Color) {
+        // the dial sweeps a half circle from the minimum on the left to the maximum on the right
+        let arc: Vec<_> = (0..=64)
+            .map(|step| {
+                let angle = PI * f64::from(step) / 64.0;
+                (-angle.cos(), angle.sin())
+            })
+            .collect();
+        let angle = PI * self.ratio();
+        let label = self.label();
+        Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([-1.1, 1.1])
+            .y_bounds([-0.3, 1.1])
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &arc,
+                    color: Color::DarkGray,
+                });
+                ctx.draw(&CanvasLine {
+                    x1: 0.0,
+                    y1: 0.0,
+                    x2: -0.9 * angle.cos(),
+                    y2: 0.9 * angle.sin(),
+                    color,
+                });
+                ctx.print(-0.2, -0.25, Line::from(label.clone()));
+            })
+            .render(area, buf);
+    }
+}
+
+impl Kind for Gauge {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let color = self.settings.thresholds.color(self.value);
+        match self.settings.shape {
+            Shape::Bar => UIGauge::default()
+                .gauge_style(Style::default().fg(color))
+                .ratio(self.ratio())
+                .label(self.label())
+                .render(area, buf),
+            Shape::VerticalBar => self.render_vertical_bar(area, buf, color),
+            Shape::Dial => self.render_dial(area, buf, color),
+        }
+    }
+
+    fn prompt(&self) -> String {
+        String::from("Gauges are constant")
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Some(value) = value.try_get_value().and_then(|value| value.try_to_f64()) {
+            self.value = value;
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> Size {
+        match self.settings.shape {
+            Shape::Bar => Size {
+                width: 2,
+                height: 1,
+            },
+            Shape::VerticalBar => Size {
+                width: 1,
+                height: 2,
+            },
+            Shape::Dial => Size {
+                width: 2,
+                height: 2,
+            },
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+}
+
+pub struct Builder {
+    pub settings: Settings,
+}
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, key: &Key, value: &Value) -> BuildResult {
+        let Value::Leaf(value) = value else {
+            return BuildResult::None;
+        };
+        if key.as_str().starts_with('.') {
+            return BuildResult::None;
+        }
+        value.try_to_f64().map_or(BuildResult::None, |value| {
+            BuildResult::Complete(Box::new(Gauge {
+                value,
+                settings: self.settings.clone(),
+            }))
+        })
+    }
+
+    fn priority(&self) -> i32 {
+        0
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::{Buffer, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    symbols::Marker,
+    text::Span,
+    widgets::{Axis, Chart, Dataset, GraphType, Paragraph, Widget},
+};
+
+use crate::{
+    backend::{Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    util::NTValue,
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+// how far in the window can be zoomed, as a fraction of it
+const MAX_ZOOM: u32 = 16;
+
+#[derive(Clone, Debug)]
+pub struct Settings {
+    /// how long samples are kept for
+    pub window: Duration,
+    /// a fixed range for the y axis, otherwise it fits the visible samples
+    pub y_range: Option<(f64, f64)>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(30),
+            y_range: None,
+        }
+    }
+}
+
+/// The samples of a number from within a window of time.
+#[derive(Clone, Debug)]
+pub struct History {
+    samples: VecDeque<(Instant, f64)>,
+    window: Duration,
+}
+
+impl History {
+    pub const fn new(window: Duration) -> Self {
+        Self {
+            samples: VecDeque::new(),
+            window,
+        }
+    }
+
+    /// Add a sample, dropping the ones from more than a window before the end of the timeline, which
+    /// stays where it was paused so a paused graph keeps what it shows.
+    pub fn push(&mut self, value: f64, end: Instant) {
+        self.samples.push_back((Instant::now(), value));
+        while self
+            .samples
+            .front()
+            .is_some_and(|(time, _)| end.saturating_duration_since(*time) > self.window)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    pub fn latest(&self, end: Instant) -> Option<f64> {
+        self.samples
+            .iter()
+            .rev()
+            .find(|(time, _)| *time <= end)
+            .map(|(_, value)| *value)
+    }
+
+    /// Samples within the span before the end, as the seconds before the end and the value.
+    pub fn points(&self, end: Instant, span: f64) -> Vec<(f64, f64)> {
+        let mut points: Vec<_> = self
+            .samples
+            .iter()
+            .filter(|(time, _)| *time <= end)
+            .map(|(time, value)| (-end.duration_since(*time).as_secs_f64(), *value))
+            .skip_while(|(x, _)| *x < -span)
+            .collect();
+        // the value holds until it changes, so carry the last one up to the end
+        if let Some(value) = self.latest(end) {
+            points.push((0.0, value));
+        }
+        points
+    }
+}
+
+/// Which part of the history is shown, which can be paused and zoomed into.
+#[derive(Clone, Debug)]
+pub struct Timeline {
+    // only the latest window / zoom of the history is shown
+    zoom: u32,
+    paused_at: Option<Instant>,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self {
+            zoom: 1,
+            paused_at: None,
+        }
+    }
+}
+
+impl Timeline {
+    pub fn end(&self) -> Instant {
+        self.paused_at.unwrap_or_else(Instant::now)
+    }
+
+    pub fn span(&self, window: Duration) -> f64 {
+        window.as_secs_f64() / f64::from(self.zoom)
+    }
+
+    pub const fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// `p` pauses and `+`/`-` zoom, returning whether the key was used.
+    pub fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('p') => {
+                self.paused_at = match self.paused_at {
+                    Some(_) => None,
+                    None => Some(Instant::now()),
+                };
+            }
+            KeyCode::Char('+') => self.zoom = (self.zoom * 2).min(MAX_ZOOM),
+            KeyCode::Char('-') => self.zoom = (self.zoom / 2).max(1),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// The fixed range if there is one, otherwise a range that fits all the points.
+pub fn y_bounds<'a>(
+    y_range: Option<(f64, f64)>,
+    points: impl IntoIterator<Item = &'a (f64, f64)>,
+) -> (f64, f64) {
+    if let Some(range) = y_range {
+        return range;
+    }
+    let (min, max) = points
+        .into_iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), (_, y)| {
+            (min.min(*y), max.max(*y))
+        });
+    if min > max {
+        return (-1.0, 1.0);
+    }
+    let padding = if (max - min).abs() < f64::EPSILON {
+        1.0
+    } else {
+        (max - min) * 0.1
+    };
+    (min - padding, max + padding)
+}
+
+pub fn format_number(value: f64) -> String {
+    format!("{value:.2}")
+}
+
+pub fn render_chart(
+    datasets: Vec<Dataset<'_>>,
+    span: f64,
+    (low, high): (f64, f64),
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    Chart::new(datasets)
+        .x_axis(
+            Axis::default()
+                .bounds([-span, 0.0])
+                .labels(vec![Span::raw(format!("-{span:.0}s")), Span::raw("0")]),
+        )
+        .y_axis(Axis::default().bounds([low, high]).labels(vec![
+            Span::raw(format_number(low)),
+            Span::raw(format_number(high)),
+        ]))
+        .render(area, buf);
+}
+
+/// A line graph of a number over time, like `LinePlot`.
+#[derive(Clone, Debug)]
+pub struct Graph {
+    history: History,
+    settings: Settings,
+    timeline: Timeline,
+}
+
+impl Kind for Graph {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+
+        let end = self.timeline.end();
+        let span = self.timeline.span(self.settings.window);
+        let points = self.history.points(end, span);
+
+        let mut header = self
+            .history
+            .latest(end)
+            .map_or_else(String::new, format_number);
+        if self.timeline.is_paused() {
+            header.push_str(" (paused)");
+        }
+        Paragraph::new(header).render(chunks[0], buf);
+
+        let bounds = y_bounds(self.settings.y_range, &points);
+        let dataset = Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Cyan))
+            .data(&points);
+        render_chart(vec![dataset], span, bounds, chunks[1], buf);
+    }
+
+    fn prompt(&self) -> String {
+        String::from("Graphs can't be edited, p pauses and +/- zoom while the cursor is on one")
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Some(value) = value.try_get_value().and_then(|value| value.try_to_f64()) {
+            self.history.push(value, self.timeline.end());
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 2,
+            height: 2,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+
+    fn handle_key(&mut self, _path: &Path, code: KeyCode) -> Option<Write> {
+        self.timeline.handle_key(code).then(Write::default)
+    }
+}
+
+#[derive(Default)]
+pub struct Builder {
+    pub settings: Settings,
+}
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, key: &Key, value: &Value) -> BuildResult {
+        let Value::Leaf(value) = value else {
+            return BuildResult::None;
+        };
+        if key.as_str().starts_with('.') {
+            return BuildResult::None;
+        }
+        value.try_to_f64().map_or(BuildResult::None, |value| {
+            let mut history = History::new(self.settings.window);
+            history.push(value, Instant::now());
+            BuildResult::Complete(Box::new(Graph {
+                history,
+                settings: self.settings.clone(),
+                timeline: Timeline::default(),
+            }))
+        })
+    }
+
+    /// Behind the number widget, so numbers are graphed by cycling to it or from the config.
+    fn priority(&self) -> i32 {
+        -5
+    }
+}
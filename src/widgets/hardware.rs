@@ -0,0 +1,422 @@
+use std::f64::consts::PI;
+
+use ratatui::{
+    prelude::{Alignment, Buffer, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    symbols::Marker,
+    text::Line,
+    widgets::{
+        canvas::{Canvas, Line as CanvasLine, Points},
+        Block, Paragraph, Widget,
+    },
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Entry, Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    graph::format_number,
+    util::NTValue,
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+const TYPES: [&str; 9] = [
+    "Gyro",
+    "Encoder",
+    "Motor Controller",
+    "DifferentialDrive",
+    "MecanumDrive",
+    "Accelerometer",
+    "3AxisAccelerometer",
+    "Ultrasonic",
+    "Analog Input",
+];
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a hardware sendable")]
+    NotHardware,
+    #[error("{r#type} is missing {field}")]
+    MissingField {
+        r#type: &'static str,
+        field: &'static str,
+    },
+    #[error("{0:?} isn't a number")]
+    NotNumber(String),
+    #[error("{0} isn't between -1 and 1")]
+    OutOfRange(f64),
+}
+
+/// The readings of a sensor or the output of an actuator, by its `.type`.
+#[derive(Clone, Debug)]
+enum Device {
+    /// degrees clockwise
+    Gyro(f64),
+    Encoder {
+        speed: f64,
+        distance: f64,
+        distance_per_tick: f64,
+    },
+    /// from -1 to 1
+    MotorController(f64),
+    DifferentialDrive {
+        left: f64,
+        right: f64,
+    },
+    MecanumDrive {
+        front_left: f64,
+        front_right: f64,
+        rear_left: f64,
+        rear_right: f64,
+    },
+    /// in gs
+    Accelerometer(f64),
+    ThreeAxisAccelerometer {
+        x: f64,
+        y: f64,
+        z: f64,
+    },
+    /// in inches
+    Ultrasonic(f64),
+    /// in volts
+    AnalogInput(f64),
+}
+
+impl Device {
+    // also returns whether the robot lets the dashboard control the device
+    fn parse(value: &Value) -> Result<(Self, bool), Error> {
+        let nodes = value.try_get_nodes().ok_or(Error::NotHardware)?;
+        let r#type = nodes
+            .try_get_value(".type")
+            .and_then(|value| value.try_to_string())
+            .ok_or(Error::NotHardware)?;
+        let r#type = *TYPES
+            .iter()
+            .find(|known| **known == r#type)
+            .ok_or(Error::NotHardware)?;
+        let number = |field: &'static str| {
+            nodes
+                .try_get_value(field)
+                .and_then(|value| value.try_to_f64())
+                .ok_or(Error::MissingField { r#type, field })
+        };
+        let device = match r#type {
+            "Gyro" => Self::Gyro(number("Value")?),
+            "Encoder" => Self::Encoder {
+                speed: number("Speed")?,
+                distance: number("Distance")?,
+                distance_per_tick: number("Distance per Tick")?,
+            },
+            "Motor Controller" => Self::MotorController(number("Value")?),
+            "DifferentialDrive" => Self::DifferentialDrive {
+                left: number("Left Motor Speed")?,
+                right: number("Right Motor Speed")?,
+            },
+            "MecanumDrive" => Self::MecanumDrive {
+                front_left: number("Front Left Motor Speed")?,
+                front_right: number("Front Right Motor Speed")?,
+                rear_left: number("Rear Left Motor Speed")?,
+                rear_right: number("Rear Right Motor Speed")?,
+            },
+            "Accelerometer" => Self::Accelerometer(number("Value")?),
+            "3AxisAccelerometer" => Self::ThreeAxisAccelerometer {
+                x: number("X")?,
+                y: number("Y")?,
+                z: number("Z")?,
+            },
+            "Ultrasonic" => Self::Ultrasonic(number("Value")?),
+            _ => Self::AnalogInput(number("Value")?),
+        };
+        let controllable = nodes
+            .try_get_value(".controllable")
+            .and_then(|value| value.as_bool())
+            .unwrap_or_default();
+        Ok((device, controllable))
+    }
+}
+
+fn speed_color(speed: f64) -> Color {
+    if speed < 0.0 {
+        Color::Red
+    } else {
+        Color::Green
+    }
+}
+
+// the number of cells filled out of the given amount for a speed from -1 to 1
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn filled(speed: f64, half: u16) -> u16 {
+    (speed.abs().min(1.0) * f64::from(half)).round() as u16
+}
+
+/// A bar growing right from the middle for forwards and left for backwards, labelled above.
+fn render_speed_bar(speed: f64, area: Rect, buf: &mut Buffer) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+    Paragraph::new(format_number(speed))
+        .alignment(Alignment::Center)
+        .render(chunks[0], buf);
+    let bar = chunks[1];
+    let half = bar.width / 2;
+    let filled = filled(speed, half);
+    let x = if speed < 0.0 {
+        bar.x + half - filled
+    } else {
+        bar.x + half
+    };
+    Block::default()
+        .style(Style::default().bg(speed_color(speed)))
+        .render(
+            Rect {
+                x,
+                width: filled,
+                ..bar
+            },
+            buf,
+        );
+}
+
+/// A wheel as a bar growing up from the middle for forwards and down for backwards.
+fn render_wheel(speed: f64, area: Rect, buf: &mut Buffer) {
+    let half = area.height / 2;
+    let filled = filled(speed, half);
+    let y = if speed < 0.0 {
+        area.y + half
+    } else {
+        area.y + half - filled
+    };
+    Block::default()
+        .style(Style::default().bg(Color::DarkGray))
+        .render(area, buf);
+    Block::default()
+        .style(Style::default().bg(speed_color(speed)))
+        .render(
+            Rect {
+                y,
+                height: filled,
+                ..area
+            },
+            buf,
+        );
+    Paragraph::new(format_number(speed))
+        .alignment(Alignment::Center)
+        .render(Rect { height: 1, ..area }, buf);
+}
+
+/// Wheels down both sides of the drive base, left then right for each row.
+fn render_drive(rows: &[(f64, f64)], area: Rect, buf: &mut Buffer) {
+    #[allow(clippy::cast_possible_truncation)]
+    let constraints = vec![Constraint::Ratio(1, rows.len() as u32); rows.len()];
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area);
+    for ((left, right), row) in rows.iter().zip(row_areas.iter()) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(30),
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
+            ])
+            .split(*row);
+        render_wheel(*left, columns[0], buf);
+        render_wheel(*right, columns[2], buf);
+    }
+}
+
+/// A compass with north up and the needle turning clockwise.
+fn render_compass(angle: f64, area: Rect, buf: &mut Buffer) {
+    let circle: Vec<_> = (0..64)
+        .map(|step| {
+            let angle = 2.0 * PI * f64::from(step) / 64.0;
+            (angle.cos(), angle.sin())
+        })
+        .collect();
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let label = format!("{}°", format_number(angle));
+    Canvas::default()
+        .marker(Marker::Braille)
+        .x_bounds([-1.1, 1.1])
+        .y_bounds([-1.1, 1.1])
+        .paint(|ctx| {
+            ctx.draw(&Points {
+                coords: &circle,
+                color: Color::DarkGray,
+            });
+            ctx.draw(&CanvasLine {
+                x1: 0.0,
+                y1: 0.0,
+                x2: 0.9 * sin,
+                y2: 0.9 * cos,
+                color: Color::Cyan,
+            });
+            ctx.print(-0.3, -0.4, Line::from(label.clone()));
+        })
+        .render(area, buf);
+}
+
+fn render_readings(readings: &[(&str, f64, &str)], area: Rect, buf: &mut Buffer) {
+    let lines: Vec<_> = readings
+        .iter()
+        .map(|(name, value, unit)| Line::from(format!("{name:<9}{} {unit}", format_number(*value))))
+        .collect();
+    Paragraph::new(lines).render(area, buf);
+}
+
+/// A sensor, motor or drive base published by `LiveWindow` or `SmartDashboard`.
+#[derive(Clone, Debug)]
+pub struct Hardware {
+    device: Device,
+    controllable: bool,
+    error: Option<String>,
+    is_finished: bool,
+}
+
+impl Kind for Hardware {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        match self.device {
+            Device::Gyro(angle) => render_compass(angle, area, buf),
+            Device::Encoder {
+                speed,
+                distance,
+                distance_per_tick,
+            } => render_readings(
+                &[
+                    ("speed", speed, "/s"),
+                    ("distance", distance, ""),
+                    ("per tick", distance_per_tick, ""),
+                ],
+                area,
+                buf,
+            ),
+            Device::MotorController(speed) => render_speed_bar(speed, area, buf),
+            Device::DifferentialDrive { left, right } => {
+                render_drive(&[(left, right)], area, buf);
+            }
+            Device::MecanumDrive {
+                front_left,
+                front_right,
+                rear_left,
+                rear_right,
+            } => render_drive(
+                &[(front_left, front_right), (rear_left, rear_right)],
+                area,
+                buf,
+            ),
+            Device::Accelerometer(value) => render_readings(&[("accel", value, "g")], area, buf),
+            Device::ThreeAxisAccelerometer { x, y, z } => {
+                render_readings(&[("x", x, "g"), ("y", y, "g"), ("z", z, "g")], area, buf);
+            }
+            Device::Ultrasonic(range) => render_readings(&[("range", range, "in")], area, buf),
+            Device::AnalogInput(voltage) => {
+                render_readings(&[("voltage", voltage, "V")], area, buf);
+            }
+        }
+    }
+
+    fn prompt(&self) -> String {
+        let Device::MotorController(_) = self.device else {
+            return String::from("Only motor controllers can be edited");
+        };
+        if !self.controllable {
+            return String::from("The robot doesn't allow this motor controller to be controlled");
+        }
+        let prompt = "Enter a speed from -1 to 1";
+        let Some(error) = &self.error else {
+            return prompt.to_string();
+        };
+        format!("{error}. {prompt}")
+    }
+
+    fn update(&mut self, path: &Path, text: &str) -> Write {
+        let Device::MotorController(speed) = &mut self.device else {
+            return Write::default();
+        };
+        if !self.controllable {
+            return Write::default();
+        }
+        let new_speed = match text.trim().parse::<f64>() {
+            Ok(new_speed) if (-1.0..=1.0).contains(&new_speed) => new_speed,
+            Ok(new_speed) => {
+                self.error = Some(Error::OutOfRange(new_speed).to_string());
+                return Write::default();
+            }
+            Err(_) => {
+                self.error = Some(Error::NotNumber(text.to_string()).to_string());
+                return Write::default();
+            }
+        };
+        *speed = new_speed;
+        self.error = None;
+        self.is_finished = true;
+        let mut path = path.clone();
+        path.push("Value");
+        Write::one(Entry {
+            path,
+            value: network_tables::Value::from(new_speed),
+        })
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Ok((device, controllable)) = Device::parse(value) {
+            self.device = device;
+            self.controllable = controllable;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.error = None;
+        self.is_finished =
+            !(self.controllable && matches!(self.device, Device::MotorController(_)));
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    fn size(&self) -> Size {
+        let (width, height) = match self.device {
+            Device::Gyro(_) | Device::Encoder { .. } | Device::ThreeAxisAccelerometer { .. } => {
+                (1, 2)
+            }
+            Device::DifferentialDrive { .. } | Device::MecanumDrive { .. } => (2, 2),
+            Device::MotorController(_)
+            | Device::Accelerometer(_)
+            | Device::Ultrasonic(_)
+            | Device::AnalogInput(_) => (1, 1),
+        };
+        Size { width, height }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+}
+
+pub struct Builder;
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match Device::parse(value) {
+            Ok((device, controllable)) => BuildResult::Complete(Box::new(Hardware {
+                device,
+                controllable,
+                error: None,
+                is_finished: false,
+            })),
+            Err(Error::NotHardware) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
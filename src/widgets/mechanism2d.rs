@@ -0,0 +1,221 @@
+use ratatui::{
+    prelude::{Buffer, Rect},
+    style::Color,
+    symbols::Marker,
+    widgets::{
+        canvas::{Canvas, Context, Line as CanvasLine},
+        Widget,
+    },
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Key, Path, Write},
+    widget_tree::{Nodes, Value},
+};
+
+use super::{
+    util::{self, NTValue},
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+// heavier ligaments are drawn with more parallel strokes, up to this many
+const MAX_STROKES: u8 = 3;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a Mechanism2d")]
+    NotMechanism2d,
+    #[error("missing dims")]
+    MissingDims,
+    #[error("{name} is missing its {field}")]
+    Missing { name: String, field: &'static str },
+}
+
+fn number(nodes: &Nodes, name: &str, field: &'static str) -> Result<f64, Error> {
+    nodes
+        .try_get_value(field)
+        .and_then(|value| value.try_to_f64())
+        .ok_or_else(|| Error::Missing {
+            name: name.to_string(),
+            field,
+        })
+}
+
+fn color(nodes: &Nodes, key: &str) -> Option<Color> {
+    nodes.try_get_value(key)?.try_to_string()?.parse().ok()
+}
+
+/// A segment attached to the end of its parent, with an angle relative to it.
+#[derive(Clone, Debug)]
+struct Ligament {
+    /// in degrees, counterclockwise
+    angle: f64,
+    length: f64,
+    color: Color,
+    weight: f64,
+    ligaments: Vec<Self>,
+}
+
+impl Ligament {
+    fn draw(&self, ctx: &mut Context<'_>, (x, y): (f64, f64), parent_angle: f64, stroke_gap: f64) {
+        let angle = parent_angle + self.angle;
+        let (sin, cos) = angle.to_radians().sin_cos();
+        let end = (self.length.mul_add(cos, x), self.length.mul_add(sin, y));
+        // the default weight of 10 pixels is about a single braille dot
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let strokes = ((self.weight / 10.0).round() as u8).clamp(1, MAX_STROKES);
+        for stroke in 0..strokes {
+            // spread the strokes out perpendicular to the ligament
+            let offset = (f64::from(stroke) - f64::from(strokes - 1) / 2.0) * stroke_gap;
+            ctx.draw(&CanvasLine {
+                x1: offset.mul_add(-sin, x),
+                y1: offset.mul_add(cos, y),
+                x2: offset.mul_add(-sin, end.0),
+                y2: offset.mul_add(cos, end.1),
+                color: self.color,
+            });
+        }
+        for ligament in &self.ligaments {
+            ligament.draw(ctx, end, angle, stroke_gap);
+        }
+    }
+}
+
+/// The ligaments under a table, which are the subtables with a `.type` of line.
+fn ligaments(nodes: &Nodes) -> Result<Vec<Ligament>, Error> {
+    let mut found = Vec::new();
+    for node in nodes.iter() {
+        let Some(nodes) = util::sendable(&node.value, "line") else {
+            continue;
+        };
+        let name = node.key.as_str();
+        found.push(Ligament {
+            angle: number(nodes, name, "angle")?,
+            length: number(nodes, name, "length")?,
+            color: color(nodes, "color").unwrap_or(Color::White),
+            weight: number(nodes, name, "weight").unwrap_or(10.0),
+            ligaments: ligaments(nodes)?,
+        });
+    }
+    Ok(found)
+}
+
+#[derive(Clone, Debug)]
+struct Root {
+    x: f64,
+    y: f64,
+    ligaments: Vec<Ligament>,
+}
+
+/// A `WPILib` `Mechanism2d`, like an arm or an elevator, drawn from its roots and ligaments.
+#[derive(Clone, Debug)]
+pub struct Mechanism2d {
+    width: f64,
+    height: f64,
+    background: Option<Color>,
+    roots: Vec<Root>,
+}
+
+impl Mechanism2d {
+    fn parse(value: &Value) -> Result<Self, Error> {
+        let nodes = util::sendable(value, "Mechanism2d").ok_or(Error::NotMechanism2d)?;
+        let dims = nodes
+            .try_get_value("dims")
+            .and_then(|dims| dims.try_to_f64_array())
+            .ok_or(Error::MissingDims)?;
+        let [width, height] = dims[..] else {
+            return Err(Error::MissingDims);
+        };
+        let mut roots = Vec::new();
+        // every other subtable is a root
+        for node in nodes.iter() {
+            let Value::Branch(nodes) = &node.value else {
+                continue;
+            };
+            let name = node.key.as_str();
+            roots.push(Root {
+                x: number(nodes, name, "x")?,
+                y: number(nodes, name, "y")?,
+                ligaments: ligaments(nodes)?,
+            });
+        }
+        Ok(Self {
+            width,
+            height,
+            background: color(nodes, "backgroundColor"),
+            roots,
+        })
+    }
+}
+
+impl Kind for Mechanism2d {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        // about the width of a braille dot, so strokes sit next to each other
+        let stroke_gap = self.width / (f64::from(area.width.max(1)) * 2.0);
+        let mut canvas = Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([0.0, self.width])
+            .y_bounds([0.0, self.height]);
+        if let Some(background) = self.background {
+            canvas = canvas.background_color(background);
+        }
+        canvas
+            .paint(|ctx| {
+                for root in &self.roots {
+                    for ligament in &root.ligaments {
+                        ligament.draw(ctx, (root.x, root.y), 0.0, stroke_gap);
+                    }
+                }
+            })
+            .render(area, buf);
+    }
+
+    fn prompt(&self) -> String {
+        String::from("Mechanisms are constant")
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Ok(mechanism) = Self::parse(value) {
+            *self = mechanism;
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 2,
+            height: 2,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+}
+
+pub struct Builder;
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match Mechanism2d::parse(value) {
+            Ok(mechanism) => BuildResult::Complete(Box::new(mechanism)),
+            Err(Error::NotMechanism2d) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
@@ -1,7 +1,196 @@
+pub mod alerts;
+pub mod boolean_box;
+pub mod command;
+pub mod field2d;
+pub mod gauge;
+pub mod graph;
+pub mod hardware;
+pub mod mechanism2d;
+pub mod number;
+pub mod pid_controller;
+pub mod plot;
+pub mod pneumatics;
+pub mod power_distribution;
+pub mod scheduler;
 pub mod sendable_chooser;
 pub mod simple;
+pub mod subsystem;
 pub mod tabs;
 pub mod util;
 mod widget;
 
+use std::{cmp::Ordering, time::Duration};
+
+use crate::config::{self, Options};
+
 pub use widget::{BuildResult, Builder, Kind, Size, State, Widget};
+
+pub fn builders() -> Vec<Box<dyn Builder>> {
+    vec![
+        Box::new(simple::Builder),
+        Box::new(number::Builder::default()),
+        Box::new(boolean_box::Builder::default()),
+        Box::new(graph::Builder::default()),
+        Box::new(sendable_chooser::Builder),
+        Box::new(tabs::Builder),
+        Box::new(field2d::Builder::default()),
+        Box::new(mechanism2d::Builder),
+        Box::new(pid_controller::Builder),
+        Box::new(command::Builder),
+        Box::new(scheduler::Builder),
+        Box::new(subsystem::Builder),
+        Box::new(hardware::Builder),
+        Box::new(pneumatics::Builder),
+        Box::new(power_distribution::Builder),
+        Box::new(alerts::Builder),
+    ]
+}
+
+/// The builder for a widget kind named in the config.
+pub fn configured(kind: &str, options: &Options) -> Result<Box<dyn Builder>, config::Error> {
+    if let Some(builder) = without_options(kind) {
+        options.check(&[])?;
+        return Ok(builder);
+    }
+    let builder: Box<dyn Builder> = match kind {
+        "number" => {
+            options.check(&["step"])?;
+            let step = positive(options, "step", 1.0)?;
+            Box::new(number::Builder { step })
+        }
+        "boolean" => {
+            options.check(&[
+                "true_color",
+                "false_color",
+                "true_label",
+                "false_label",
+                "momentary_ms",
+            ])?;
+            let default = boolean_box::Settings::default();
+            Box::new(boolean_box::Builder {
+                settings: boolean_box::Settings {
+                    true_color: options.get_or("true_color", default.true_color)?,
+                    false_color: options.get_or("false_color", default.false_color)?,
+                    true_label: options.get_or("true_label", default.true_label)?,
+                    false_label: options.get_or("false_label", default.false_label)?,
+                    momentary: options.get("momentary_ms")?.map(Duration::from_millis),
+                },
+            })
+        }
+        "graph" => {
+            options.check(&["window_s", "min", "max"])?;
+            Box::new(graph::Builder {
+                settings: graph_settings(options)?,
+            })
+        }
+        "plot" => {
+            options.check(&["series", "window_s", "min", "max"])?;
+            let pattern = options
+                .get("series")?
+                .ok_or_else(|| config::Error::MissingOption(String::from("series")))?;
+            Box::new(plot::Builder {
+                pattern,
+                settings: graph_settings(options)?,
+            })
+        }
+        "bar" => gauge(gauge::Shape::Bar, options)?,
+        "vbar" => gauge(gauge::Shape::VerticalBar, options)?,
+        "dial" => gauge(gauge::Shape::Dial, options)?,
+        "field2d" => {
+            options.check(&["width", "height", "robot_size"])?;
+            let default = field2d::Settings::default();
+            Box::new(field2d::Builder {
+                settings: field2d::Settings {
+                    width: positive(options, "width", default.width)?,
+                    height: positive(options, "height", default.height)?,
+                    robot_size: positive(options, "robot_size", default.robot_size)?,
+                },
+            })
+        }
+        _ => return Err(config::Error::UnknownKind(kind.to_string())),
+    };
+    Ok(builder)
+}
+
+/// The builder for a kind that doesn't take any options.
+fn without_options(kind: &str) -> Option<Box<dyn Builder>> {
+    let builder: Box<dyn Builder> = match kind {
+        "simple" => Box::new(simple::Builder),
+        "mechanism2d" => Box::new(mechanism2d::Builder),
+        "pid" => Box::new(pid_controller::Builder),
+        "command" => Box::new(command::Builder),
+        "scheduler" => Box::new(scheduler::Builder),
+        "subsystem" => Box::new(subsystem::Builder),
+        "hardware" => Box::new(hardware::Builder),
+        "pneumatics" => Box::new(pneumatics::Builder),
+        "power" => Box::new(power_distribution::Builder),
+        "alerts" => Box::new(alerts::Builder),
+        "chooser" => Box::new(sendable_chooser::Builder),
+        "tabs" => Box::new(tabs::Builder),
+        _ => return None,
+    };
+    Some(builder)
+}
+
+fn graph_settings(options: &Options) -> Result<graph::Settings, config::Error> {
+    let default = graph::Settings::default();
+    let window = match options.get::<f64>("window_s")? {
+        // a negative, NaN, infinite or empty window can't be converted or graphed
+        Some(seconds) => Duration::try_from_secs_f64(seconds)
+            .ok()
+            .filter(|window| !window.is_zero())
+            .ok_or_else(|| config::Error::InvalidOption {
+                name: String::from("window_s"),
+                value: seconds.to_string(),
+            })?,
+        None => default.window,
+    };
+    let y_range = match (options.get("min")?, options.get("max")?) {
+        (Some(min), Some(max)) => {
+            check_range(min, max)?;
+            Some((min, max))
+        }
+        (None, None) => None,
+        _ => return Err(config::Error::MissingOption(String::from("min and max"))),
+    };
+    Ok(graph::Settings { window, y_range })
+}
+
+// a range with max at or below min, or NaN, would be empty or upside down
+fn check_range(min: f64, max: f64) -> Result<(), config::Error> {
+    if max.partial_cmp(&min) != Some(Ordering::Greater) {
+        return Err(config::Error::InvalidOption {
+            name: String::from("max"),
+            value: max.to_string(),
+        });
+    }
+    Ok(())
+}
+
+// a step or size of zero or less, or NaN, would never change anything or leave nothing to draw
+fn positive(options: &Options, name: &str, default: f64) -> Result<f64, config::Error> {
+    let value: f64 = options.get_or(name, default)?;
+    if !(value > 0.0 && value.is_finite()) {
+        return Err(config::Error::InvalidOption {
+            name: name.to_string(),
+            value: value.to_string(),
+        });
+    }
+    Ok(value)
+}
+
+fn gauge(shape: gauge::Shape, options: &Options) -> Result<Box<dyn Builder>, config::Error> {
+    options.check(&["min", "max", "thresholds", "unit"])?;
+    let min = options.get_or("min", 0.0)?;
+    let max = options.get_or("max", 1.0)?;
+    check_range(min, max)?;
+    Ok(Box::new(gauge::Builder {
+        settings: gauge::Settings {
+            shape,
+            min,
+            max,
+            thresholds: options.get_or("thresholds", gauge::Thresholds::default())?,
+            unit: options.get_or("unit", String::new())?,
+        },
+    }))
+}
@@ -0,0 +1,168 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::{Buffer, Rect},
+    widgets::{Paragraph, Widget},
+};
+
+use crate::{
+    backend::{Entry, Key, Path, Write},
+    state::add::EntryType,
+    widget_tree::Value,
+};
+
+use super::{
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+/// An editable int, float or double, which keeps the type it was published with.
+#[derive(Clone, Debug)]
+pub struct Number {
+    value: network_tables::Value,
+    r#type: EntryType,
+    step: f64,
+    error: Option<String>,
+    is_finished: bool,
+}
+
+impl Number {
+    fn write(&mut self, path: &Path, value: network_tables::Value) -> Write {
+        self.value = value.clone();
+        Write::one(Entry {
+            path: path.clone(),
+            value,
+        })
+    }
+
+    // integers step by a whole number, and at least 1 so a fractional step still does something
+    #[allow(clippy::cast_possible_truncation)]
+    fn int_step(&self) -> i64 {
+        (self.step.round() as i64).max(1)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn stepped(&self, steps: i64) -> Option<network_tables::Value> {
+        #[allow(clippy::cast_precision_loss)]
+        let change = self.step * steps as f64;
+        let value = match self.r#type {
+            EntryType::Int => network_tables::Value::from(
+                self.value
+                    .as_i64()?
+                    .saturating_add(self.int_step().saturating_mul(steps)),
+            ),
+            EntryType::Float => network_tables::Value::from((self.value.as_f64()? + change) as f32),
+            _ => network_tables::Value::from(self.value.as_f64()? + change),
+        };
+        Some(value)
+    }
+}
+
+impl Kind for Number {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let widget = Paragraph::new(self.value.to_string());
+        widget.render(area, buf);
+    }
+
+    fn prompt(&self) -> String {
+        let step = if self.r#type == EntryType::Int {
+            self.int_step().to_string()
+        } else {
+            self.step.to_string()
+        };
+        let prompt = format!("Enter a new {} value, ↑/↓ to step by {step}", self.r#type);
+        match &self.error {
+            Some(error) => format!("{error}. {prompt}"),
+            None => prompt,
+        }
+    }
+
+    fn update(&mut self, path: &Path, text: &str) -> Write {
+        match self.r#type.parse(text) {
+            Ok(value) => {
+                self.error = None;
+                self.is_finished = true;
+                self.write(path, value)
+            }
+            Err(error) => {
+                self.error = Some(error.to_string());
+                Write::default()
+            }
+        }
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Value::Leaf(value) = &value {
+            if let Some(r#type) = EntryType::of(value) {
+                self.value = value.clone();
+                self.r#type = r#type;
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.error = None;
+        self.is_finished = false;
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 1,
+            height: 1,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+
+    fn handle_key(&mut self, path: &Path, code: KeyCode) -> Option<Write> {
+        let steps = match code {
+            KeyCode::Up | KeyCode::Char('+') => 1,
+            KeyCode::Down | KeyCode::Char('-') => -1,
+            _ => return None,
+        };
+        let value = self.stepped(steps)?;
+        Some(self.write(path, value))
+    }
+}
+
+pub struct Builder {
+    pub step: f64,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self { step: 1.0 }
+    }
+}
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, key: &Key, value: &Value) -> BuildResult {
+        let Value::Leaf(value) = value else {
+            return BuildResult::None;
+        };
+        if key.as_str().starts_with('.') {
+            return BuildResult::None;
+        }
+        match EntryType::of(value) {
+            Some(r#type @ (EntryType::Int | EntryType::Float | EntryType::Double)) => {
+                BuildResult::Complete(Box::new(Number {
+                    value: value.clone(),
+                    r#type,
+                    step: self.step,
+                    error: None,
+                    is_finished: false,
+                }))
+            }
+            _ => BuildResult::None,
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        5
+    }
+}
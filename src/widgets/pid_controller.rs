@@ -0,0 +1,185 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::{Buffer, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Entry, Key, Path, Write},
+    state::add::EntryType,
+    widget_tree::Value,
+};
+
+use super::{
+    util,
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+// izone was only added in 2024, so older robots don't publish it
+const FIELDS: [&str; 4] = ["p", "i", "d", "setpoint"];
+const OPTIONAL_FIELDS: [&str; 1] = ["izone"];
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a PIDController")]
+    NotPIDController,
+    #[error("missing {0}")]
+    MissingField(&'static str),
+    #[error("{0} isn't a number")]
+    NotNumber(&'static str),
+}
+
+#[derive(Clone, Debug)]
+struct Field {
+    name: &'static str,
+    value: network_tables::Value,
+    r#type: EntryType,
+}
+
+fn field(name: &'static str, value: network_tables::Value) -> Result<Field, Error> {
+    match EntryType::of(&value) {
+        Some(r#type @ (EntryType::Int | EntryType::Float | EntryType::Double)) => Ok(Field {
+            name,
+            value,
+            r#type,
+        }),
+        _ => Err(Error::NotNumber(name)),
+    }
+}
+
+/// The gains and setpoint of a `WPILib` `PIDController`, each of which can be edited.
+#[derive(Clone, Debug)]
+pub struct PIDController {
+    fields: Vec<Field>,
+    selected: usize,
+    error: Option<String>,
+}
+
+impl PIDController {
+    fn parse(value: &Value) -> Result<Self, Error> {
+        let nodes = util::sendable(value, "PIDController").ok_or(Error::NotPIDController)?;
+        let mut fields = Vec::new();
+        for name in FIELDS {
+            let value = nodes.try_get_value(name).ok_or(Error::MissingField(name))?;
+            fields.push(field(name, value)?);
+        }
+        for name in OPTIONAL_FIELDS {
+            if let Some(value) = nodes.try_get_value(name) {
+                fields.push(field(name, value)?);
+            }
+        }
+        Ok(Self {
+            fields,
+            selected: 0,
+            error: None,
+        })
+    }
+}
+
+impl Kind for PIDController {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let lines: Vec<_> = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let style = if index == self.selected {
+                    Style::default().fg(Color::LightBlue)
+                } else {
+                    Style::default()
+                };
+                Line::from(vec![
+                    Span::styled(format!("{:<9}", field.name), style),
+                    Span::styled(field.value.to_string(), style),
+                ])
+            })
+            .collect();
+        Paragraph::new(lines).render(area, buf);
+    }
+
+    fn prompt(&self) -> String {
+        let field = &self.fields[self.selected];
+        let prompt = format!(
+            "Enter a new {} for {}, ↑/↓ to pick another, Esc when done",
+            field.r#type, field.name
+        );
+        match &self.error {
+            Some(error) => format!("{error}. {prompt}"),
+            None => prompt,
+        }
+    }
+
+    fn update(&mut self, path: &Path, text: &str) -> Write {
+        let field = &mut self.fields[self.selected];
+        match field.r#type.parse(text) {
+            Ok(value) => {
+                self.error = None;
+                field.value = value.clone();
+                let mut path = path.clone();
+                path.push(field.name);
+                Write::one(Entry { path, value })
+            }
+            Err(error) => {
+                self.error = Some(error.to_string());
+                Write::default()
+            }
+        }
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Ok(controller) = Self::parse(value) {
+            self.fields = controller.fields;
+            self.selected = self.selected.min(self.fields.len() - 1);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.error = None;
+    }
+
+    // several gains are usually tuned at once, so editing lasts until escaped
+    fn is_finished(&self) -> bool {
+        false
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 1,
+            height: 2,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+
+    fn handle_key(&mut self, _path: &Path, code: KeyCode) -> Option<Write> {
+        match code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(self.fields.len() - 1),
+            _ => return None,
+        }
+        self.error = None;
+        Some(Write::default())
+    }
+}
+
+pub struct Builder;
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match PIDController::parse(value) {
+            Ok(controller) => BuildResult::Complete(Box::new(controller)),
+            Err(Error::NotPIDController) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
@@ -0,0 +1,171 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::{Buffer, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Dataset, GraphType, Paragraph, Widget},
+};
+
+use crate::{
+    backend::{Key, Path, Pattern, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    graph::{self, History, Timeline},
+    util::NTValue,
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+const COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Green,
+    Color::Red,
+    Color::Blue,
+];
+
+#[derive(Clone, Debug)]
+struct Series {
+    path: Path,
+    history: History,
+}
+
+/// Overlays every numeric entry matching a pattern, like a setpoint and its measurement.
+#[derive(Clone, Debug)]
+pub struct Plot {
+    pattern: Pattern,
+    series: Vec<Series>,
+    settings: graph::Settings,
+    timeline: Timeline,
+}
+
+impl Kind for Plot {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+
+        let end = self.timeline.end();
+        let span = self.timeline.span(self.settings.window);
+        let points: Vec<_> = self
+            .series
+            .iter()
+            .map(|series| series.history.points(end, span))
+            .collect();
+
+        // the legend, with the latest value of each series
+        let mut legend = Vec::new();
+        for (series, color) in self.series.iter().zip(COLORS.iter().cycle()) {
+            let name = series.path.rest.last().unwrap_or(&series.path.first);
+            let value = series
+                .history
+                .latest(end)
+                .map_or_else(String::new, graph::format_number);
+            legend.push(Span::styled(
+                format!("{name} {value}  "),
+                Style::default().fg(*color),
+            ));
+        }
+        if self.timeline.is_paused() {
+            legend.push(Span::raw("(paused)"));
+        }
+        Paragraph::new(Line::from(legend)).render(chunks[0], buf);
+
+        let bounds = graph::y_bounds(self.settings.y_range, points.iter().flatten());
+        let datasets = points
+            .iter()
+            .zip(COLORS.iter().cycle())
+            .map(|(points, color)| {
+                Dataset::default()
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(*color))
+                    .data(points)
+            })
+            .collect();
+        graph::render_chart(datasets, span, bounds, chunks[1], buf);
+    }
+
+    fn prompt(&self) -> String {
+        String::from("Plots can't be edited, p pauses and +/- zoom while the cursor is on one")
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, _value: &Value) {}
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 3,
+            height: 2,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+
+    fn handle_key(&mut self, _path: &Path, code: KeyCode) -> Option<Write> {
+        self.timeline.handle_key(code).then(Write::default)
+    }
+
+    fn is_observer(&self) -> bool {
+        true
+    }
+
+    fn update_observed(&mut self, path: &Path, value: &network_tables::Value) {
+        if !self.pattern.matches(path) {
+            return;
+        }
+        let Some(value) = value.try_to_f64() else {
+            return;
+        };
+        let index = if let Some(index) = self.series.iter().position(|series| &series.path == path)
+        {
+            index
+        } else {
+            self.series.push(Series {
+                path: path.clone(),
+                history: History::new(self.settings.window),
+            });
+            self.series.len() - 1
+        };
+        self.series[index].history.push(value, self.timeline.end());
+    }
+}
+
+pub struct Builder {
+    pub pattern: Pattern,
+    pub settings: graph::Settings,
+}
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, key: &Key, _value: &Value) -> BuildResult {
+        if key.as_str().starts_with('.') {
+            return BuildResult::None;
+        }
+        BuildResult::Complete(Box::new(Plot {
+            pattern: self.pattern.clone(),
+            series: Vec::new(),
+            settings: self.settings.clone(),
+            timeline: Timeline::default(),
+        }))
+    }
+
+    fn priority(&self) -> i32 {
+        0
+    }
+}
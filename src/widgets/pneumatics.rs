@@ -0,0 +1,224 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::{Alignment, Buffer, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Paragraph, Widget},
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Entry, Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    util::NTValue,
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+// the states double solenoids and relays cycle through, in order
+const DOUBLE_SOLENOID_STATES: [&str; 3] = ["Off", "Forward", "Reverse"];
+const RELAY_STATES: [&str; 4] = ["Off", "On", "Forward", "Reverse"];
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a pneumatics sendable")]
+    NotPneumatics,
+    #[error("{r#type} is missing {field}")]
+    MissingField {
+        r#type: &'static str,
+        field: &'static str,
+    },
+    #[error("{r#type} can't be {state:?}")]
+    UnknownState { r#type: &'static str, state: String },
+}
+
+#[derive(Clone, Debug)]
+enum Device {
+    Solenoid(bool),
+    /// an index into `DOUBLE_SOLENOID_STATES`
+    DoubleSolenoid(usize),
+    /// an index into `RELAY_STATES`
+    Relay(usize),
+    Compressor {
+        enabled: bool,
+        /// true while the pressure is low, so the tank isn't full yet
+        pressure_switch: bool,
+    },
+}
+
+impl Device {
+    fn parse(value: &Value) -> Result<(Self, bool), Error> {
+        let nodes = value.try_get_nodes().ok_or(Error::NotPneumatics)?;
+        let r#type = nodes
+            .try_get_value(".type")
+            .and_then(|value| value.try_to_string())
+            .ok_or(Error::NotPneumatics)?;
+        let boolean = |r#type, field| {
+            nodes
+                .try_get_value(field)
+                .and_then(|value| value.as_bool())
+                .ok_or(Error::MissingField { r#type, field })
+        };
+        let state = |r#type, states: &[&str]| {
+            let state = nodes
+                .try_get_value("Value")
+                .and_then(|value| value.try_to_string())
+                .ok_or(Error::MissingField {
+                    r#type,
+                    field: "Value",
+                })?;
+            states
+                .iter()
+                .position(|known| *known == state)
+                .ok_or(Error::UnknownState { r#type, state })
+        };
+        let device = match r#type.as_str() {
+            "Solenoid" => Self::Solenoid(boolean("Solenoid", "Value")?),
+            "Double Solenoid" => {
+                Self::DoubleSolenoid(state("Double Solenoid", &DOUBLE_SOLENOID_STATES)?)
+            }
+            "Relay" => Self::Relay(state("Relay", &RELAY_STATES)?),
+            "Compressor" => Self::Compressor {
+                enabled: boolean("Compressor", "Enabled")?,
+                pressure_switch: boolean("Compressor", "Pressure switch")?,
+            },
+            _ => return Err(Error::NotPneumatics),
+        };
+        let controllable = nodes
+            .try_get_value(".controllable")
+            .and_then(|value| value.as_bool())
+            .unwrap_or_default();
+        Ok((device, controllable))
+    }
+}
+
+fn state_appearance(state: &str) -> (Color, Vec<Line<'_>>) {
+    let color = match state {
+        "Off" => Color::DarkGray,
+        "Reverse" => Color::Red,
+        _ => Color::Green,
+    };
+    (color, vec![Line::from(state)])
+}
+
+/// A solenoid, relay or compressor, which actuators can be toggled from when the robot allows it.
+#[derive(Clone, Debug)]
+pub struct Pneumatics {
+    device: Device,
+    controllable: bool,
+}
+
+impl Kind for Pneumatics {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let (color, lines) = match self.device {
+            Device::Solenoid(true) => (Color::Green, vec![Line::from("on")]),
+            Device::Solenoid(false) => (Color::DarkGray, vec![Line::from("off")]),
+            Device::DoubleSolenoid(index) => state_appearance(DOUBLE_SOLENOID_STATES[index]),
+            Device::Relay(index) => state_appearance(RELAY_STATES[index]),
+            Device::Compressor {
+                enabled,
+                pressure_switch,
+            } => (
+                if enabled {
+                    Color::Green
+                } else {
+                    Color::DarkGray
+                },
+                vec![
+                    Line::from(if enabled { "running" } else { "stopped" }),
+                    Line::from(if pressure_switch { "low" } else { "full" }),
+                ],
+            ),
+        };
+        let style = Style::default().bg(color).fg(Color::Black);
+        Block::default().style(style).render(area, buf);
+        Paragraph::new(lines)
+            .style(style)
+            .alignment(Alignment::Center)
+            .render(area, buf);
+    }
+
+    fn prompt(&self) -> String {
+        if matches!(self.device, Device::Compressor { .. }) {
+            String::from("Compressors are constant")
+        } else if self.controllable {
+            String::from("Press Enter or space on the actuator to toggle it")
+        } else {
+            String::from("The robot doesn't allow this actuator to be controlled")
+        }
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Ok((device, controllable)) = Device::parse(value) {
+            self.device = device;
+            self.controllable = controllable;
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 1,
+            height: 1,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+
+    fn handle_key(&mut self, path: &Path, code: KeyCode) -> Option<Write> {
+        if !self.controllable || !matches!(code, KeyCode::Enter | KeyCode::Char(' ')) {
+            return None;
+        }
+        let value = match &mut self.device {
+            Device::Solenoid(on) => {
+                *on = !*on;
+                network_tables::Value::from(*on)
+            }
+            Device::DoubleSolenoid(index) => {
+                *index = (*index + 1) % DOUBLE_SOLENOID_STATES.len();
+                network_tables::Value::from(DOUBLE_SOLENOID_STATES[*index])
+            }
+            Device::Relay(index) => {
+                *index = (*index + 1) % RELAY_STATES.len();
+                network_tables::Value::from(RELAY_STATES[*index])
+            }
+            Device::Compressor { .. } => return None,
+        };
+        let mut path = path.clone();
+        path.push("Value");
+        Some(Write::one(Entry { path, value }))
+    }
+}
+
+pub struct Builder;
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match Device::parse(value) {
+            Ok((device, controllable)) => BuildResult::Complete(Box::new(Pneumatics {
+                device,
+                controllable,
+            })),
+            Err(Error::NotPneumatics) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
@@ -0,0 +1,186 @@
+use ratatui::{
+    prelude::{Buffer, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Paragraph, Widget},
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    graph::format_number,
+    util::{self, NTValue},
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+// the roboRIO disables outputs below this voltage
+const BROWNOUT_VOLTAGE: f64 = 6.8;
+// close enough to a brownout to be worth warning about
+const LOW_VOLTAGE: f64 = 8.0;
+// channel currents are drawn out of this, the size of the largest usual breaker
+const MAX_CHANNEL_CURRENT: f64 = 40.0;
+const HIGH_CHANNEL_CURRENT: f64 = 20.0;
+// each bar and its gap take 3 columns, so a cell about 20 columns wide fits 6 of them
+const CHANNELS_PER_CELL: usize = 6;
+// the narrowest that still fits the voltage, current and temperature
+const MIN_WIDTH: usize = 2;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a PowerDistribution")]
+    NotPowerDistribution,
+    #[error("missing {0}")]
+    MissingField(&'static str),
+}
+
+/// The currents of every channel of a PDP or PDH, along with the state of the battery.
+#[derive(Clone, Debug)]
+pub struct PowerDistribution {
+    channels: Vec<f64>,
+    voltage: f64,
+    total_current: f64,
+    /// not published by `PowerDistribution` itself, only by robots that add it
+    temperature: Option<f64>,
+}
+
+impl PowerDistribution {
+    fn parse(value: &Value) -> Result<Self, Error> {
+        let nodes =
+            util::sendable(value, "PowerDistribution").ok_or(Error::NotPowerDistribution)?;
+        let number = |field: &str| {
+            nodes
+                .try_get_value(field)
+                .and_then(|value| value.try_to_f64())
+        };
+        // channels are published as Chan0, Chan1 and so on, 16 for a PDP and 24 for a PDH
+        let channels = (0..)
+            .map_while(|channel| number(&format!("Chan{channel}")))
+            .collect();
+        Ok(Self {
+            channels,
+            voltage: number("Voltage").ok_or(Error::MissingField("Voltage"))?,
+            total_current: number("TotalCurrent").ok_or(Error::MissingField("TotalCurrent"))?,
+            temperature: number("Temperature"),
+        })
+    }
+}
+
+fn current_color(current: f64) -> Color {
+    if current >= MAX_CHANNEL_CURRENT {
+        Color::Red
+    } else if current >= HIGH_CHANNEL_CURRENT {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+impl Kind for PowerDistribution {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+
+        let voltage_style = if self.voltage <= BROWNOUT_VOLTAGE {
+            Style::default().bg(Color::Red).fg(Color::Black)
+        } else if self.voltage <= LOW_VOLTAGE {
+            Style::default().bg(Color::Yellow).fg(Color::Black)
+        } else {
+            Style::default()
+        };
+        let mut header = vec![
+            Span::styled(format!("{} V", format_number(self.voltage)), voltage_style),
+            Span::raw(format!("  {} A", format_number(self.total_current))),
+        ];
+        if let Some(temperature) = self.temperature {
+            header.push(Span::raw(format!("  {} °C", format_number(temperature))));
+        }
+        let header = Line::from(header);
+        Paragraph::new(header).render(chunks[0], buf);
+
+        // tenths of an amp, since bars only take whole numbers
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let tenths = |current: f64| (current.max(0.0) * 10.0).round() as u64;
+        let bars: Vec<_> = self
+            .channels
+            .iter()
+            .enumerate()
+            .map(|(channel, current)| {
+                Bar::default()
+                    .value(tenths(*current))
+                    .text_value(format!("{current:.0}"))
+                    .label(Line::from(channel.to_string()))
+                    .style(Style::default().fg(current_color(*current)))
+                    .value_style(
+                        Style::default()
+                            .fg(Color::Black)
+                            .bg(current_color(*current)),
+                    )
+            })
+            .collect();
+        BarChart::default()
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(2)
+            .bar_gap(1)
+            .max(tenths(MAX_CHANNEL_CURRENT))
+            .render(chunks[1], buf);
+    }
+
+    fn prompt(&self) -> String {
+        String::from("Power distribution is constant")
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Ok(power_distribution) = Self::parse(value) {
+            *self = power_distribution;
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    // a PDP's 16 channels fit in 3 cells, but a PDH's 24 need 4
+    fn size(&self) -> Size {
+        Size {
+            width: self
+                .channels
+                .len()
+                .div_ceil(CHANNELS_PER_CELL)
+                .max(MIN_WIDTH),
+            height: 2,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+}
+
+pub struct Builder;
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match PowerDistribution::parse(value) {
+            Ok(power_distribution) => BuildResult::Complete(Box::new(power_distribution)),
+            Err(Error::NotPowerDistribution) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
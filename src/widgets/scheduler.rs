@@ -0,0 +1,251 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    prelude::{Buffer, Rect},
+    style::{Color, Style},
+    widgets::{List, ListItem, ListState, StatefulWidget},
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Entry, Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    util::{self, NTValue},
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a Scheduler")]
+    NotScheduler,
+    #[error("missing Names")]
+    MissingNames,
+    #[error("missing Ids")]
+    MissingIds,
+    #[error("Ids aren't all whole numbers")]
+    FractionalIds,
+    #[error("there are {names} Names but {ids} Ids")]
+    MismatchedIds { names: usize, ids: usize },
+}
+
+/// The commands the command-based `Scheduler` is running, any of which can be canceled.
+#[derive(Clone, Debug)]
+pub struct Scheduler {
+    names: Vec<String>,
+    ids: Vec<i64>,
+    /// robots before 2023 publish the ids as doubles, and expect them back the same way
+    has_double_ids: bool,
+    selected: usize,
+}
+
+// doubles hold integers exactly up to 2^53
+#[allow(clippy::cast_possible_truncation)]
+fn whole(id: f64) -> Option<i64> {
+    (id.fract() == 0.0 && id.abs() <= 9_007_199_254_740_992.0).then_some(id as i64)
+}
+
+impl Scheduler {
+    fn parse(value: &Value) -> Result<Self, Error> {
+        let nodes = util::sendable(value, "Scheduler").ok_or(Error::NotScheduler)?;
+        let names = nodes
+            .try_get_value("Names")
+            .and_then(|value| value.try_to_string_array())
+            .ok_or(Error::MissingNames)?;
+        let ids = nodes.try_get_value("Ids").ok_or(Error::MissingIds)?;
+        let (ids, has_double_ids) = if let Some(ids) = ids.try_to_i64_array() {
+            (ids, false)
+        } else {
+            let ids = ids.try_to_f64_array().ok_or(Error::MissingIds)?;
+            let ids = ids.into_iter().map(whole).collect::<Option<_>>();
+            (ids.ok_or(Error::FractionalIds)?, true)
+        };
+        if names.len() != ids.len() {
+            return Err(Error::MismatchedIds {
+                names: names.len(),
+                ids: ids.len(),
+            });
+        }
+        Ok(Self {
+            names,
+            ids,
+            has_double_ids,
+            selected: 0,
+        })
+    }
+}
+
+impl Kind for Scheduler {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        if self.names.is_empty() {
+            let items = vec![ListItem::new("no commands running")];
+            let widget = List::new(items).style(Style::default().fg(Color::DarkGray));
+            StatefulWidget::render(widget, area, buf, &mut ListState::default());
+            return;
+        }
+        let items: Vec<_> = self
+            .names
+            .iter()
+            .map(String::as_str)
+            .map(ListItem::new)
+            .collect();
+
+        let widget = List::new(items)
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().fg(Color::LightBlue));
+
+        let mut state = ListState::default().with_selected(Some(self.selected));
+
+        StatefulWidget::render(widget, area, buf, &mut state);
+    }
+
+    fn prompt(&self) -> String {
+        let Some(name) = self.names.get(self.selected) else {
+            return String::from("No commands are running, Esc when done");
+        };
+        format!("Press Enter to cancel {name}, ↑/↓ to pick another, Esc when done")
+    }
+
+    fn update(&mut self, path: &Path, _text: &str) -> Write {
+        let Some(id) = self.ids.get(self.selected) else {
+            return Write::default();
+        };
+        // the scheduler cancels every command in the array, then clears it
+        let mut path = path.clone();
+        path.push("Cancel");
+        #[allow(clippy::cast_precision_loss)]
+        let id = if self.has_double_ids {
+            network_tables::Value::from(*id as f64)
+        } else {
+            network_tables::Value::from(*id)
+        };
+        Write::one(Entry {
+            path,
+            value: network_tables::Value::Array(vec![id]),
+        })
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Ok(scheduler) = Self::parse(value) {
+            // keep the same command selected while others start and finish around it
+            let selected_id = self.ids.get(self.selected);
+            self.selected = selected_id
+                .and_then(|id| scheduler.ids.iter().position(|other| other == id))
+                .unwrap_or_else(|| self.selected.min(scheduler.ids.len().saturating_sub(1)));
+            self.names = scheduler.names;
+            self.ids = scheduler.ids;
+            self.has_double_ids = scheduler.has_double_ids;
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    // several commands may need canceling, so editing lasts until escaped
+    fn is_finished(&self) -> bool {
+        false
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 1,
+            height: 2,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+
+    fn handle_key(&mut self, _path: &Path, code: KeyCode) -> Option<Write> {
+        match code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.ids.len().saturating_sub(1));
+            }
+            _ => return None,
+        }
+        Some(Write::default())
+    }
+}
+
+pub struct Builder;
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match Scheduler::parse(value) {
+            Ok(scheduler) => BuildResult::Complete(Box::new(scheduler)),
+            Err(Error::NotScheduler) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use network_tables::Value;
+
+    use super::{Error, Scheduler};
+    use crate::{backend::Path, widget_tree, widgets::widget::Kind};
+
+    fn scheduler(ids: Value) -> Result<Scheduler, Error> {
+        Scheduler::parse(&widget_tree::Value::branch(vec![
+            (".type", Value::from("Scheduler")),
+            (
+                "Names",
+                Value::Array(vec![Value::from("Drive"), Value::from("Intake")]),
+            ),
+            ("Ids", ids),
+        ]))
+    }
+
+    // the id written to cancel the selected command
+    fn cancel(scheduler: &mut Scheduler) -> Value {
+        let path: Path = "/SmartDashboard/Scheduler".parse().unwrap();
+        let write = scheduler.update(&path, "");
+        let entry = write.entries().next().unwrap();
+        assert_eq!(entry.path.to_string(), "/SmartDashboard/Scheduler/Cancel");
+        let Value::Array(ids) = &entry.value else {
+            panic!("expected an array, got {}", entry.value);
+        };
+        ids[0].clone()
+    }
+
+    #[test]
+    fn parses_int_ids() {
+        let mut scheduler = scheduler(Value::Array(vec![Value::from(3), Value::from(7)])).unwrap();
+        assert_eq!(scheduler.ids, [3, 7]);
+        assert!(!scheduler.has_double_ids);
+        assert_eq!(cancel(&mut scheduler), Value::from(3));
+    }
+
+    #[test]
+    fn parses_double_ids_and_cancels_with_doubles() {
+        let mut scheduler =
+            scheduler(Value::Array(vec![Value::from(3.0), Value::from(7.0)])).unwrap();
+        assert_eq!(scheduler.ids, [3, 7]);
+        assert!(scheduler.has_double_ids);
+        assert_eq!(cancel(&mut scheduler), Value::from(3.0));
+    }
+
+    #[test]
+    fn rejects_bad_ids() {
+        assert!(matches!(
+            scheduler(Value::Array(vec![Value::from(3.5), Value::from(7.0)])),
+            Err(Error::FractionalIds)
+        ));
+        assert!(matches!(
+            scheduler(Value::Array(vec![Value::from(3)])),
+            Err(Error::MismatchedIds { names: 2, ids: 1 })
+        ));
+        assert!(matches!(
+            scheduler(Value::from("3, 7")),
+            Err(Error::MissingIds)
+        ));
+    }
+}
@@ -173,8 +173,15 @@ impl widget::Builder for Builder {
         let widget = SendableChooser::try_from(value);
         match widget {
             Ok(widget) => BuildResult::Complete(Box::new(widget)),
-            Err(Error::WantedNodes) => BuildResult::None,
+            // only tables that claim to be choosers are worth reporting
+            Err(Error::WantedNodes | Error::MissingType | Error::IncorrectType(_)) => {
+                BuildResult::None
+            }
             Err(error) => BuildResult::Partial(error.into()),
         }
     }
+
+    fn priority(&self) -> i32 {
+        10
+    }
 }
@@ -72,4 +72,8 @@ impl widget::Builder for Builder {
             }))
         }
     }
+
+    fn priority(&self) -> i32 {
+        0
+    }
 }
@@ -0,0 +1,213 @@
+use ratatui::{
+    prelude::{Buffer, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Paragraph, Widget},
+};
+use thiserror::Error;
+
+use crate::{
+    backend::{Key, Path, Write},
+    widget_tree::Value,
+};
+
+use super::{
+    util::{self, NTValue},
+    widget::{self, Kind, Size},
+    BuildResult,
+};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("not a Subsystem")]
+    NotSubsystem,
+    #[error("missing {0}")]
+    MissingField(&'static str),
+}
+
+/// A command-based `Subsystem`, showing whether its default command has been interrupted.
+#[derive(Clone, Debug)]
+pub struct Subsystem {
+    default: Option<String>,
+    command: Option<String>,
+}
+
+impl Subsystem {
+    fn parse(value: &Value) -> Result<Self, Error> {
+        let nodes = util::sendable(value, "Subsystem").ok_or(Error::NotSubsystem)?;
+        // WPILib publishes whether there is a command alongside its name
+        let command = |has: &'static str, name: &'static str| {
+            let has = nodes
+                .try_get_value(has)
+                .and_then(|value| value.as_bool())
+                .ok_or(Error::MissingField(has))?;
+            if !has {
+                return Ok(None);
+            }
+            nodes
+                .try_get_value(name)
+                .and_then(|value| value.try_to_string())
+                .map(Some)
+                .ok_or(Error::MissingField(name))
+        };
+        Ok(Self {
+            default: command(".hasDefault", ".default")?,
+            command: command(".hasCommand", ".command")?,
+        })
+    }
+
+    /// Whether a command other than the default one has taken over the subsystem.
+    fn is_interrupted(&self) -> bool {
+        self.command.is_some() && self.command != self.default
+    }
+}
+
+impl Kind for Subsystem {
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let style = if self.is_interrupted() {
+            Style::default().bg(Color::Yellow).fg(Color::Black)
+        } else {
+            Style::default()
+        };
+        Block::default().style(style).render(area, buf);
+        let lines = vec![
+            Line::from(format!(
+                "running {}",
+                self.command.as_deref().unwrap_or("none")
+            )),
+            Line::from(format!(
+                "default {}",
+                self.default.as_deref().unwrap_or("none")
+            )),
+        ];
+        Paragraph::new(lines).style(style).render(area, buf);
+    }
+
+    fn prompt(&self) -> String {
+        String::from("Subsystems are constant")
+    }
+
+    fn update(&mut self, _path: &Path, _text: &str) -> Write {
+        Write::default()
+    }
+
+    fn update_nt(&mut self, _key: &Key, value: &Value) {
+        if let Ok(subsystem) = Self::parse(value) {
+            *self = subsystem;
+        }
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_finished(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> Size {
+        Size {
+            width: 1,
+            height: 1,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Kind> {
+        Box::new(self.clone())
+    }
+}
+
+pub struct Builder;
+
+impl widget::Builder for Builder {
+    fn create_kind(&self, _key: &Key, value: &Value) -> BuildResult {
+        match Subsystem::parse(value) {
+            Ok(subsystem) => BuildResult::Complete(Box::new(subsystem)),
+            Err(Error::NotSubsystem) => BuildResult::None,
+            Err(error) => BuildResult::Partial(error.into()),
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use network_tables::Value;
+
+    use super::{Error, Subsystem};
+    use crate::widget_tree;
+
+    fn subsystem(fields: Vec<(&str, Value)>) -> Result<Subsystem, Error> {
+        let mut entries = vec![(".type", Value::from("Subsystem"))];
+        entries.extend(fields);
+        Subsystem::parse(&widget_tree::Value::branch(entries))
+    }
+
+    #[test]
+    fn interrupted_while_another_command_runs() {
+        let subsystem = subsystem(vec![
+            (".hasDefault", Value::from(true)),
+            (".default", Value::from("Drive")),
+            (".hasCommand", Value::from(true)),
+            (".command", Value::from("Shoot")),
+        ])
+        .unwrap();
+        assert_eq!(subsystem.default.as_deref(), Some("Drive"));
+        assert_eq!(subsystem.command.as_deref(), Some("Shoot"));
+        assert!(subsystem.is_interrupted());
+    }
+
+    #[test]
+    fn not_interrupted_by_its_default_command() {
+        let subsystem = subsystem(vec![
+            (".hasDefault", Value::from(true)),
+            (".default", Value::from("Drive")),
+            (".hasCommand", Value::from(true)),
+            (".command", Value::from("Drive")),
+        ])
+        .unwrap();
+        assert!(!subsystem.is_interrupted());
+    }
+
+    #[test]
+    fn reads_whether_it_has_commands_from_the_has_fields() {
+        // WPILib publishes the name `none` when there isn't a command, which a command could also be named
+        let subsystem = subsystem(vec![
+            (".hasDefault", Value::from(false)),
+            (".default", Value::from("none")),
+            (".hasCommand", Value::from(false)),
+            (".command", Value::from("none")),
+        ])
+        .unwrap();
+        assert_eq!(subsystem.default, None);
+        assert_eq!(subsystem.command, None);
+        assert!(!subsystem.is_interrupted());
+    }
+
+    #[test]
+    fn doesnt_need_names_without_commands() {
+        let subsystem = subsystem(vec![
+            (".hasDefault", Value::from(false)),
+            (".hasCommand", Value::from(false)),
+        ])
+        .unwrap();
+        assert_eq!(subsystem.default, None);
+        assert_eq!(subsystem.command, None);
+    }
+
+    #[test]
+    fn requires_the_has_fields_and_the_names_they_promise() {
+        assert!(matches!(
+            subsystem(vec![(".hasDefault", Value::from(false))]),
+            Err(Error::MissingField(".hasCommand"))
+        ));
+        assert!(matches!(
+            subsystem(vec![
+                (".hasDefault", Value::from(true)),
+                (".hasCommand", Value::from(false)),
+            ]),
+            Err(Error::MissingField(".default"))
+        ));
+    }
+}
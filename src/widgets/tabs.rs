@@ -6,9 +6,8 @@ use ratatui::{
 use thiserror::Error;
 
 use crate::{
-    backend::{Key, Path, PathError, Write},
+    backend::{Key, Path, Pattern, PatternError, Write},
     widget_tree::Value,
-    widgets,
 };
 
 use super::{util::NTValue, widget, BuildResult, Kind, Size};
@@ -16,7 +15,7 @@ use super::{util::NTValue, widget, BuildResult, Kind, Size};
 #[derive(Clone, Debug)]
 struct Tab {
     option: String,
-    widgets: Vec<Path>,
+    widgets: Vec<Pattern>,
 }
 
 #[derive(Clone, Debug)]
@@ -28,15 +27,15 @@ pub struct Tabs {
 
 #[derive(Clone, Debug)]
 pub enum Filter {
-    NoneOf(Vec<Path>),
-    OneOf(Vec<Path>),
+    NoneOf(Vec<Pattern>),
+    OneOf(Vec<Pattern>),
 }
 
 impl Filter {
-    pub fn contains(&self, widget: &widgets::Widget) -> bool {
+    pub fn contains(&self, path: &Path) -> bool {
         match self {
-            Self::NoneOf(paths) => !paths.contains(&widget.title),
-            Self::OneOf(paths) => paths.contains(&widget.title),
+            Self::NoneOf(patterns) => !patterns.iter().any(|pattern| pattern.matches(path)),
+            Self::OneOf(patterns) => patterns.iter().any(|pattern| pattern.matches(path)),
         }
     }
 }
@@ -61,8 +60,8 @@ pub enum Error {
     WantedValue,
     #[error("option isn't a string array")]
     OptionNotStringArray,
-    #[error("parsing error {0:?} while parsing a path")]
-    PathParse(PathError),
+    #[error("parsing error {0:?} while parsing a pattern")]
+    PatternParse(PatternError),
 }
 
 impl Tabs {
@@ -117,7 +116,7 @@ impl TryFrom<&Value> for Tabs {
 
             options.push(Tab {
                 option: option.to_string(),
-                widgets: widgets.map_err(Error::PathParse)?,
+                widgets: widgets.map_err(Error::PatternParse)?,
             });
         }
 
@@ -173,7 +172,7 @@ impl Kind for Tabs {
             self.selected = Some(index);
 
             let mut paths = self.options[index].widgets.clone();
-            paths.push(path.clone());
+            paths.push(Pattern::from(path.clone()));
 
             Write::filter(Filter::OneOf(paths))
         } else if text == "General" {
@@ -219,10 +218,14 @@ impl widget::Builder for Builder {
         let widget = Tabs::try_from(value);
         match widget {
             Ok(widget) => BuildResult::Complete(Box::new(widget)),
-            Err(error @ (Error::OptionNotStringArray | Error::PathParse(_))) => {
+            Err(error @ (Error::OptionNotStringArray | Error::PatternParse(_))) => {
                 BuildResult::Partial(error.into())
             }
             Err(_) => BuildResult::None,
         }
     }
+
+    fn priority(&self) -> i32 {
+        10
+    }
 }
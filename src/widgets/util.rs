@@ -1,8 +1,21 @@
 use network_tables::Value;
 
+use crate::widget_tree::{self, Nodes};
+
+/// The table of a sendable, if the value is a table with the given `.type`.
+pub fn sendable<'a>(value: &'a widget_tree::Value, r#type: &str) -> Option<&'a Nodes> {
+    let nodes = value.try_get_nodes()?;
+    let actual = nodes.try_get_value(".type")?.try_to_string()?;
+    (actual == r#type).then_some(nodes)
+}
+
 pub trait NTValue {
     fn try_to_string(&self) -> Option<String>;
     fn try_to_string_array(&self) -> Option<Vec<String>>;
+    /// Any of the number types as a double.
+    fn try_to_f64(&self) -> Option<f64>;
+    fn try_to_f64_array(&self) -> Option<Vec<f64>>;
+    fn try_to_i64_array(&self) -> Option<Vec<i64>>;
 }
 
 impl NTValue for Value {
@@ -21,4 +34,30 @@ impl NTValue for Value {
             None
         }
     }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn try_to_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(integer) => integer.as_i64().map(|integer| integer as f64),
+            Self::F32(float) => Some(f64::from(*float)),
+            Self::F64(double) => Some(*double),
+            _ => None,
+        }
+    }
+
+    fn try_to_f64_array(&self) -> Option<Vec<f64>> {
+        if let Self::Array(array) = self {
+            array.iter().map(NTValue::try_to_f64).collect()
+        } else {
+            None
+        }
+    }
+
+    fn try_to_i64_array(&self) -> Option<Vec<i64>> {
+        if let Self::Array(array) = self {
+            array.iter().map(Self::as_i64).collect()
+        } else {
+            None
+        }
+    }
 }
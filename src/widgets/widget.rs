@@ -1,4 +1,5 @@
 use anyhow::Error;
+use crossterm::event::KeyCode;
 use ratatui::{
     prelude::{Buffer, Rect},
     style::{Color, Style},
@@ -44,6 +45,20 @@ pub trait Kind: Debug {
     fn is_finished(&self) -> bool;
     fn size(&self) -> Size;
     fn clone_box(&self) -> Box<dyn Kind>;
+    /// Whether the widget watches entries other than the ones of its own node through `update_observed`.
+    fn is_observer(&self) -> bool {
+        false
+    }
+    /// Any entry in the tree changed, for widgets that are observers.
+    fn update_observed(&mut self, _path: &Path, _value: &network_tables::Value) {}
+    /// A key pressed while the widget is under the cursor that isn't already bound, or an arrow key while editing.
+    fn handle_key(&mut self, _path: &Path, _code: KeyCode) -> Option<Write> {
+        None
+    }
+    /// A color for the border to draw attention to the widget, shown unless the cursor is on it.
+    fn border_color(&self) -> Option<Color> {
+        None
+    }
 }
 
 #[derive(Debug)]
@@ -55,18 +70,24 @@ pub enum BuildResult {
 
 pub trait Builder {
     fn create_kind(&self, key: &Key, value: &Value) -> BuildResult;
+    /// When several builders make a widget for the same node, the highest priority one is shown first.
+    fn priority(&self) -> i32;
+    /// Whether the builder should be tried for the node at the path.
+    fn applies_to(&self, _path: &[Key]) -> bool {
+        true
+    }
 }
 
 const UNHIGHLIGHTED_COLOR: Color = Color::White;
 const HIGHLIGHTED_COLOR: Color = Color::Yellow;
 const SELECTED_COLOR: Color = Color::Magenta;
 
-impl StatefulWidget for Widget {
+impl StatefulWidget for &Widget {
     type State = State;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut State) {
         let color = match state {
-            State::Unhighlighted => UNHIGHLIGHTED_COLOR,
+            State::Unhighlighted => self.value.border_color().unwrap_or(UNHIGHLIGHTED_COLOR),
             State::Highlighted => HIGHLIGHTED_COLOR,
             State::Selected => SELECTED_COLOR,
         };
@@ -108,6 +129,18 @@ impl Widget {
         self.value.update(&self.title, text)
     }
 
+    pub fn handle_key(&mut self, code: KeyCode) -> Option<Write> {
+        self.value.handle_key(&self.title, code)
+    }
+
+    pub fn is_observer(&self) -> bool {
+        self.value.is_observer()
+    }
+
+    pub fn update_observed(&mut self, path: &Path, value: &network_tables::Value) {
+        self.value.update_observed(path, value);
+    }
+
     pub fn update_nt(&mut self, key: &Key, value: &Value) {
         self.value.update_nt(key, value);
     }